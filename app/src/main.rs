@@ -2,16 +2,22 @@ use std::time::Duration;
 
 use clap::Parser;
 use bevy::core_pipeline::bloom::Bloom;
+use bevy::ecs::system::SystemParam;
 
 pub(crate) use bevy::{prelude::*, sprite::Anchor, render::view::RenderLayers};
 pub(crate) use bevy_kira_audio::prelude::*;
 pub(crate) use bevy_lunex::*;
 pub(crate) use vleue_kinetoscope::*;
 
+pub(crate) use game_audio::*;
 pub(crate) use game_cameras::*;
+pub(crate) use game_character::*;
+pub(crate) use game_credits::*;
 pub(crate) use game_loading::*;
 pub(crate) use game_movies::*;
 pub(crate) use game_preferences::*;
+pub(crate) use game_save::*;
+pub(crate) use game_ui::*;
 pub(crate) use game_vfx::*;
 
 
@@ -27,8 +33,12 @@ enum AppState {
     MainMenu,
     /// The game creation
     NewGame,
+    /// The list of saved games
+    LoadGame,
     /// The game settings
     Settings,
+    /// The credits roll
+    Credits,
 }
 
 fn main() -> AppExit {
@@ -42,12 +52,17 @@ fn main() -> AppExit {
     #[cfg(target_arch = "wasm32")]
     {args.skip_intro = true;}
 
+    // Load the stored preferences and layer the launch arguments over them
+    let preferences_plugin = PreferencesPlugin::load(args);
+    let preferences = preferences_plugin.preferences();
+
     // Add all Bevy plugins
-    app.add_plugins(BevyPlugins(args));
+    app.add_plugins(BevyPlugins(preferences.clone()));
+    app.add_plugins(preferences_plugin);
     //app.add_plugins(UiLunexDebugPlugin::<1, 2>);
 
     // Set the correct app state
-    app.insert_state(if args.skip_intro { AppState::MainMenu } else { AppState::IntroMovie });
+    app.insert_state(if preferences.skip_intro { AppState::MainMenu } else { AppState::IntroMovie });
 
 
     // ----- PRIORITY ASSET LOADING  -----
@@ -55,9 +70,9 @@ fn main() -> AppExit {
     let mut priority_assets = PriorityAssets::default();
 
     // Load the game intro if required
-    if !args.skip_intro {
+    if !preferences.skip_intro {
         let intro = AnimatedImageLoader::load_now_from_bytes(
-            if args.lowram { include_bytes!("../../assets/movies/intro_720p.webp") } else { include_bytes!("../../assets/movies/intro_1080p.webp") },
+            if preferences.graphics.lowram { include_bytes!("../../assets/movies/intro_720p.webp") } else { include_bytes!("../../assets/movies/intro_1080p.webp") },
             "webp", &mut app).expect("Priority load failed");
         priority_assets.video.insert("intro".to_string(), intro);
     }
@@ -72,9 +87,44 @@ fn main() -> AppExit {
     app.add_systems(OnEnter(AppState::IntroMovie), IntroScene::spawn).add_systems(OnExit(AppState::IntroMovie), despawn_scene::<IntroScene>);
     app.add_systems(OnEnter(AppState::MainMenu), MainMenuScene::spawn).add_systems(OnExit(AppState::MainMenu), despawn_scene::<MainMenuScene>);
     app.add_systems(OnEnter(AppState::NewGame), NewGameScene::spawn).add_systems(OnExit(AppState::NewGame), despawn_scene::<NewGameScene>);
-    app.add_systems(OnEnter(AppState::Settings), SettingsScene::spawn).add_systems(OnExit(AppState::Settings), despawn_scene::<SettingsScene>);
-
-    app.add_plugins((VFXPlugin, ShowcaseCameraPlugin, MoviePlugin));
+    app.add_systems(OnEnter(AppState::LoadGame), LoadGameScene::spawn).add_systems(OnExit(AppState::LoadGame), despawn_scene::<LoadGameScene>);
+    app.add_systems(OnEnter(AppState::Settings), (SettingsScene::spawn, SettingsSnapshot::take)).add_systems(OnExit(AppState::Settings), (despawn_scene::<SettingsScene>, SettingsSnapshot::clear));
+    app.add_systems(OnEnter(AppState::Credits), CreditsScene::spawn).add_systems(OnExit(AppState::Credits), despawn_scene::<CreditsScene>);
+
+    // Settings screen tabs
+    app.add_sub_state::<SettingsTab>();
+    app.add_systems(Update, (
+        SettingsTab::keyboard.run_if(in_state(AppState::Settings)),
+        (SettingsTab::highlight, SettingsPage::spawn).run_if(state_changed::<SettingsTab>),
+        SettingsOption::system.run_if(resource_changed::<GamePreferences>),
+        WindowConfirmPrompt::system.run_if(in_state(SettingsTab::Window)),
+    ));
+
+    // Intro skip prompt
+    app.add_systems(Update, IntroScene::prompt.run_if(in_state(AppState::IntroMovie)));
+
+    // Credits screen
+    app.add_systems(Update, CreditsRoll::system.run_if(in_state(AppState::Credits)));
+
+    // Back navigation between the screens
+    app.init_resource::<Navigation>();
+    app.add_systems(Update, (Navigation::record, Navigation::input.run_if(not(in_state(AppState::IntroMovie)))));
+    app.add_observer(Navigation::back);
+
+    // Music of the menu screens, shared so it keeps playing between them
+    app.add_plugins(MusicPlugin(MusicDirector::new()
+        .silence(AppState::IntroMovie)
+        .track(AppState::MainMenu, "audio/main_menu.ogg")
+        .track(AppState::NewGame, "audio/main_menu.ogg")
+        .track(AppState::LoadGame, "audio/main_menu.ogg")
+        .track(AppState::Settings, "audio/main_menu.ogg")
+        .track(AppState::Credits, "audio/main_menu.ogg")
+    ));
+
+    // Playtime of the active save, menus do not count
+    app.add_systems(Update, ActiveSave::playtime.run_if(resource_exists::<ActiveSave>.and(AppState::in_gameplay)));
+
+    app.add_plugins((VFXPlugin, ShowcaseCameraPlugin, MoviePlugin, WidgetPlugin, CharacterPlugin, SavePlugin, CreditsPlugin, MixerPlugin));
 
     app.run()
 }
@@ -94,7 +144,17 @@ fn preload(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn(AssetLock { assets: vec![
         asset_server.load::<AudioSource>("audio/intro.ogg").untyped(),
         asset_server.load::<AudioSource>("audio/main_menu.ogg").untyped(),
+        asset_server.load::<AudioSource>("audio/ui_ping.ogg").untyped(),
     ]});
+
+    // Assign the interface sounds, variations of the same ping
+    let ping = asset_server.load::<AudioSource>("audio/ui_ping.ogg");
+    commands.insert_resource(UiSounds::new()
+        .clip(UiSound::Hover, UiSoundClip::new(ping.clone()).volume(0.35).rate(1.5))
+        .clip(UiSound::Click, UiSoundClip::new(ping.clone()))
+        .clip(UiSound::Back, UiSoundClip::new(ping.clone()).volume(0.8).rate(0.8))
+        .clip(UiSound::Error, UiSoundClip::new(ping).volume(0.8).rate(0.5))
+    );
 }
 
 /// This system spawns & setups the basic camera with cursor
@@ -121,8 +181,7 @@ fn spawn_camera(mut commands: Commands, asset_server: Res<AssetServer>, mut atla
             // Change the scale
             Transform::from_scale(Vec3::new(0.45, 0.45, 1.0)),
             
-            // Make this cursor gamepad controlled
-            //GamepadCursor::new(),
+            // Gamepad control is added by InputMode once the stick is used
             
             // Change the sprite
             Sprite {
@@ -147,6 +206,107 @@ fn despawn_scene<S: Component>(mut commands: Commands, query: Query<Entity, With
     }
 }
 
+/// History of the visited app states and open overlays used for back navigation
+#[derive(Resource, Default)]
+struct Navigation {
+    /// States to return to, the last one is the previous state
+    history: Vec<AppState>,
+    /// Overlays of the current state, closed before leaving it
+    overlays: Vec<Entity>,
+    /// The ongoing transition was caused by going back
+    popping: bool,
+}
+impl Navigation {
+    /// Open an overlay that is closed by going back before the state is left.
+    fn push_overlay(&mut self, entity: Entity) {
+        self.overlays.push(entity);
+    }
+    /// System for remembering the states that were left
+    fn record(mut events: EventReader<StateTransitionEvent<AppState>>, mut navigation: ResMut<Navigation>) {
+        for event in events.read() {
+            // Overlays are part of the scene and despawn with it
+            navigation.overlays.clear();
+            let (Some(exited), Some(entered)) = (&event.exited, &event.entered) else { continue };
+            if exited == entered { continue; }
+            if std::mem::take(&mut navigation.popping) { continue; }
+
+            // Returning to a visited state forgets everything after it, the main menu is the root
+            if let Some(index) = navigation.history.iter().position(|state| state == entered) {
+                navigation.history.truncate(index);
+            } else if *entered == AppState::MainMenu {
+                navigation.history.clear();
+            } else if *exited != AppState::IntroMovie {
+                navigation.history.push(exited.clone());
+            }
+        }
+    }
+    /// System for going back with Escape or the gamepad East button
+    fn input(mut commands: Commands, keys: Res<ButtonInput<KeyCode>>, gamepads: Query<&Gamepad>) {
+        if keys.just_pressed(KeyCode::Escape) || gamepads.iter().any(|gamepad| gamepad.just_pressed(GamepadButton::East)) {
+            commands.trigger(GoBack);
+        }
+    }
+    /// Observer dismissing the topmost dialog or returning to the previous state
+    fn back(
+        _: Trigger<GoBack>,
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        mut navigation: ResMut<Navigation>,
+        mut screen: Screen,
+        dialogs: Query<&Dialog>,
+    ) {
+        // Skip the overlays that were already closed by their own buttons
+        while let Some(&overlay) = navigation.overlays.last() {
+            let Ok(dialog) = dialogs.get(overlay) else {
+                navigation.overlays.pop();
+                continue;
+            };
+            // A dialog without a cancel button stays open until one of its buttons is chosen
+            if dialog.cancel.is_some() {
+                commands.trigger(UiSound::Back);
+                commands.trigger_targets(DismissDialog, overlay);
+                navigation.overlays.pop();
+            }
+            return;
+        }
+
+        // Leaving the settings with changes asks what to do with them
+        if screen.has_unsaved_settings() {
+            commands.trigger(UiSound::Back);
+            let dialog = SettingsSnapshot::dialog(&mut commands, &asset_server);
+            navigation.push_overlay(dialog);
+        } else if let Some(previous) = navigation.history.pop() {
+            commands.trigger(UiSound::Back);
+            navigation.popping = true;
+            screen.next.set(previous);
+        } else if *screen.state.get() == AppState::MainMenu {
+            // There is nowhere to go back to, so ask about quitting
+            commands.trigger(UiSound::Back);
+            let dialog = MainMenuScene::quit_dialog(&mut commands, &asset_server);
+            navigation.push_overlay(dialog);
+        }
+    }
+}
+
+/// Current and next app state with the settings needed to decide where going back leads
+#[derive(SystemParam)]
+struct Screen<'w> {
+    state: Res<'w, State<AppState>>,
+    next: ResMut<'w, NextState<AppState>>,
+    preferences: Res<'w, GamePreferences>,
+    snapshot: Option<Res<'w, SettingsSnapshot>>,
+}
+impl Screen<'_> {
+    /// The settings screen is open with changes that were not applied or reverted
+    fn has_unsaved_settings(&self) -> bool {
+        *self.state.get() == AppState::Settings && self.snapshot.as_ref().is_some_and(|snapshot| snapshot.0 != *self.preferences)
+    }
+}
+
+/// Triggered to close the topmost overlay or return to the previous screen
+#[derive(Event, Clone, Copy, Debug)]
+struct GoBack;
+
 
 #[derive(Component)]
 struct IntroScene;
@@ -162,25 +322,117 @@ impl IntroScene {
         )).with_children(|ui| {
 
             // Start the intro together with music
-            ui.spawn((
+            let movie = ui.spawn((
                 UiLayout::solid().size((1920.0, 1080.0)).scaling(Scaling::Fill).pack(),
-                Movie::play(priority_assets.video.get("intro").unwrap().clone(), asset_server.load("audio/intro.ogg")).playback(MoviePlayback::Stop)
+                Movie::play(priority_assets.video.get("intro").unwrap().clone(), asset_server.load("audio/intro.ogg"))
+                    .playback(MoviePlayback::Stop)
+                    .subtitles(asset_server.load("movies/intro.vtt")),
+                HoldToSkip::default(),
+
+            // Add observer that will change the state once the movie ends or is skipped
+            )).observe(|trigger: Trigger<MovieEnded>, mut next: ResMut<NextState<AppState>>| {
+                match trigger.reason {
+                    MovieEndReason::Skipped => info!("Intro skipped"),
+                    MovieEndReason::Failed => warn!("Intro failed to play"),
+                    MovieEndReason::Completed => {},
+                }
+                next.set(AppState::MainMenu);
+            }).id();
+
+            // Spawn the subtitles of the intro
+            ui.spawn((
+                UiLayout::window().pos(Rl((50.0, 90.0))).anchor(Anchor::BottomCenter).pack(),
+                UiDepth::Add(5.0),
+                UiColor::from(Color::BEVYPUNK_YELLOW),
+                SubtitleLayer::new(movie),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextFont {
+                    font: asset_server.load("fonts/rajdhani/Rajdhani-SemiBold.ttf"),
+                    font_size: 48.0,
+                    ..default()
+                },
+            ));
+
+            // Spawn the skip prompt, shown while the skip is held
+            ui.spawn((
+                UiLayout::window().pos(Rl((96.0, 92.0))).anchor(Anchor::BottomRight).size(Rl((16.0, 6.0))).pack(),
+                UiDepth::Add(5.0),
+                Visibility::Hidden,
+                SkipPrompt,
+            )).with_children(|ui| {
+                ui.spawn((
+                    UiLayout::window().pos(Rl((100.0, 0.0))).anchor(Anchor::TopRight).pack(),
+                    UiColor::from(Color::BEVYPUNK_RED),
+                    UiTextSize::from(Rh(55.0)),
+                    Text2d::new("HOLD TO SKIP"),
+                    TextFont {
+                        font: asset_server.load("fonts/rajdhani/Rajdhani-Bold.ttf"),
+                        font_size: 48.0,
+                        ..default()
+                    },
+                ));
 
-            // Add observer that will change the state once the movie ends
-            )).observe(|_: Trigger<MovieEnded>, mut next: ResMut<NextState<AppState>>| next.set(AppState::MainMenu) );
+                // Spawn the progress bar
+                ui.spawn((
+                    UiLayout::window().y(Rl(80.0)).size(Rl((100.0, 20.0))).pack(),
+                    Sprite::from_color(Color::WHITE, Vec2::ONE),
+                    UiColor::from(Color::BEVYPUNK_RED.with_alpha(0.2)),
+                )).with_children(|ui| {
+                    ui.spawn((
+                        IntroScene::fill_layout(0.0),
+                        Sprite::from_color(Color::WHITE, Vec2::ONE),
+                        UiColor::from(Color::BEVYPUNK_YELLOW),
+                        SkipPromptFill,
+                    ));
+                });
+            });
         });
     }
+    /// Layout of the skip progress bar fill
+    fn fill_layout(progress: f32) -> UiLayout {
+        UiLayout::window().size(Rl((progress * 100.0, 100.0))).pack()
+    }
+    /// System for showing the skip prompt and filling its bar with the hold progress
+    fn prompt(
+        mut commands: Commands,
+        skips: Query<&HoldToSkip, Changed<HoldToSkip>>,
+        mut prompts: Query<&mut Visibility, With<SkipPrompt>>,
+        fills: Query<Entity, With<SkipPromptFill>>,
+    ) {
+        let Some(skip) = skips.iter().next() else { return };
+        for mut visibility in &mut prompts {
+            visibility.set_if_neq(if skip.progress > 0.0 { Visibility::Inherited } else { Visibility::Hidden });
+        }
+        for entity in &fills {
+            commands.entity(entity).insert(IntroScene::fill_layout(skip.progress));
+        }
+        commands.trigger(RecomputeUiLayout);
+    }
 }
 
+/// Marker for the hold to skip prompt of [`IntroScene`]
+#[derive(Component)]
+struct SkipPrompt;
+
+/// Marker for the progress bar fill of [`SkipPrompt`]
+#[derive(Component)]
+struct SkipPromptFill;
+
 
 #[derive(Component)]
 struct MainMenuScene;
 impl MainMenuScene {
-    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>, audio: Res<Audio>) {
-        // Start playing the music
-        if !audio.is_playing_sound() {
-            audio.play(asset_server.load("audio/main_menu.ogg")).looped().fade_in(AudioTween::new(Duration::new(2, 0), AudioEasing::OutPowf(2.0)));
-        }
+    /// Spawns the dialog confirming the app exit
+    fn quit_dialog(commands: &mut Commands, asset_server: &AssetServer) -> Entity {
+        Dialog::new("Quit game").body("Do you really want to leave Night City?").buttons(["Quit", "Cancel"]).default_button(1).cancel_button(1)
+            .spawn(commands, asset_server)
+            .insert(MainMenuScene)
+            .observe(|trigger: Trigger<DialogChosen>, mut exit: EventWriter<AppExit>| {
+                // Close the app
+                if trigger.0 == 0 { exit.write(AppExit::Success); }
+            }).id()
+    }
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>, store: Res<SaveStore>) {
 
         // Create UI
         commands.spawn((
@@ -233,111 +485,62 @@ impl MainMenuScene {
                     let gap = 3.0;
                     let size = 14.0;
                     let mut offset = 0.0;
+                    let has_saves = !store.slots().is_empty();
                     for button in ["Continue", "New Game", "Load Game", "Settings", "Additional Content", "Credits", "Quit Game"] {
 
-                        // Spawn the button
-                        let mut button_entity = ui.spawn((
-                            Name::new(button),
-                            UiLayout::window().y(Rl(offset)).size(Rl((100.0, size))).pack(),
-                            Visibility::Visible,
-                            OnHoverSetCursor::new(bevy::window::SystemCursorIcon::Pointer),
-                        ));
-                        button_entity.with_children(|ui| {
-                            // Spawn the image
-                            ui.spawn((
-                                // You can define layouts for multiple states
-                                UiLayout::new(vec![
-                                    (UiBase::id(), UiLayout::window().full()),
-                                    (UiHover::id(), UiLayout::window().x(Rl(10.0)).full())
-                                ]),
-                                // Like this you can enable a state
-                                UiHover::new().forward_speed(20.0).backward_speed(4.0),
-                                // You can specify colors for multiple states
-                                UiColor::new(vec![
-                                    (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.15)),
-                                    (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
-                                ]),
-                                Sprite {
-                                    image: asset_server.load("images/ui/components/button_symetric_sliced.png"),
-                                    // Here we enable sprite slicing
-                                    image_mode: SpriteImageMode::Sliced(TextureSlicer { border: BorderRect::all(32.0), ..default() }),
-                                    ..default()
-                                },
-                                // Make sure it does not cover the bounding zone of parent
-                                Pickable::IGNORE,
-                            )).with_children(|ui| {
-
-                                // Spawn the text
-                                ui.spawn((
-                                    // For text always use window layout to position it
-                                    UiLayout::window().pos((Rh(40.0), Rl(50.0))).anchor(Anchor::CenterLeft).pack(),
-                                    UiColor::new(vec![
-                                        (UiBase::id(), Color::BEVYPUNK_RED),
-                                        (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
-                                    ]),
-                                    UiHover::new().forward_speed(20.0).backward_speed(4.0),
-                                    // You can control the size of the text
-                                    UiTextSize::from(Rh(60.0)),
-                                    // You can attach text like this
-                                    Text2d::default(),
-                                    TextAnimator::new(button.to_ascii_uppercase()).function(decryption_animation).duration(1.0),
-                                    TextFont {
-                                        font: asset_server.load("fonts/rajdhani/Rajdhani-Medium.ttf"),
-                                        font_size: 64.0,
-                                        ..default()
-                                    },
-                                    // Make sure it does not cover the bounding zone of parent
-                                    Pickable::IGNORE,
-                                ));
-
-                                // Spawn the fluff
-                                ui.spawn((
-                                    // For text always use window layout to position it
-                                    UiLayout::window().pos(Rl((90.0, 50.0))).anchor(Anchor::CenterRight).pack(),
-                                    UiColor::new(vec![
-                                        (UiBase::id(), Color::BEVYPUNK_BLUE.with_alpha(0.2)),
-                                        (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
-                                    ]),
-                                    UiHover::new().forward_speed(20.0).backward_speed(4.0),
-                                    // You can control the size of the text
-                                    UiTextSize::from(Rh(60.0)),
-                                    // You can attach text like this
-                                    Text2d::new("<-"),
-                                    TextFont {
-                                        font: asset_server.load("fonts/rajdhani/Rajdhani-Bold.ttf"),
-                                        font_size: 64.0,
-                                        ..default()
-                                    },
-                                    // Make sure it does not cover the bounding zone of parent
-                                    Pickable::IGNORE,
-                                ));
-                            });
-
-                        // Enable the transition on hover
-                        }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>);
+                        // Spawn the button, there is nothing to continue without saves
+                        let mut button_entity = MenuButton::new(button).fluff("<-").animated().disabled(button == "Continue" && !has_saves)
+                            // You can define layouts for multiple states
+                            .layouts(UiLayout::window().full(), UiLayout::window().x(Rl(10.0)).full())
+                            .spawn(ui, &asset_server, UiLayout::window().y(Rl(offset)).size(Rl((100.0, size))).pack());
 
                         // Assign a functionality to the buttons
                         match button {
+                            "Continue" => {
+                                button_entity.observe(|_: Trigger<ButtonPressed>, mut commands: Commands, store: Res<SaveStore>, mut next: ResMut<NextState<AppState>>| {
+                                    // Resume the most recent save
+                                    if let Some(slot) = store.newest() { resume_save(slot, &mut commands, &mut next); }
+                                });
+                            },
                             "New Game" => {
-                                button_entity.observe(|_: Trigger<Pointer<Click>>, mut next: ResMut<NextState<AppState>>| {
-                                    // Change the state to settings
+                                button_entity.observe(|_: Trigger<ButtonPressed>, mut commands: Commands, store: Res<SaveStore>, active: Option<Res<ActiveSave>>, mut next: ResMut<NextState<AppState>>| {
+                                    // Write back the save being played, the new game gets its own slot and a fresh character
+                                    if let Some(active) = active {
+                                        if let Err(error) = active.store(&store) { warn!("Failed to store save {}: {error}", active.slot); }
+                                        commands.remove_resource::<ActiveSave>();
+                                        commands.insert_resource(CharacterAppearance::default());
+                                    }
+                                    // Change the state to game creation
                                     next.set(AppState::NewGame);
                                 });
                             },
+                            "Load Game" => {
+                                button_entity.observe(|_: Trigger<ButtonPressed>, mut next: ResMut<NextState<AppState>>| {
+                                    // Change the state to load game
+                                    next.set(AppState::LoadGame);
+                                });
+                            },
                             "Settings" => {
-                                button_entity.observe(|_: Trigger<Pointer<Click>>, mut next: ResMut<NextState<AppState>>| {
+                                button_entity.observe(|_: Trigger<ButtonPressed>, mut next: ResMut<NextState<AppState>>| {
                                     // Change the state to settings
                                     next.set(AppState::Settings);
                                 });
                             },
+                            "Credits" => {
+                                button_entity.observe(|_: Trigger<ButtonPressed>, mut next: ResMut<NextState<AppState>>| {
+                                    // Change the state to credits
+                                    next.set(AppState::Credits);
+                                });
+                            },
                             "Quit Game" => {
-                                button_entity.observe(|_: Trigger<Pointer<Click>>, mut exit: EventWriter<AppExit>| {
-                                    // Close the app
-                                    exit.write(AppExit::Success);
+                                button_entity.observe(|_: Trigger<ButtonPressed>, mut commands: Commands, asset_server: Res<AssetServer>, mut navigation: ResMut<Navigation>| {
+                                    // Ask before closing the app
+                                    let dialog = MainMenuScene::quit_dialog(&mut commands, &asset_server);
+                                    navigation.push_overlay(dialog);
                                 });
                             },
                             _ => {
-                                button_entity.observe(|c_trigger: Trigger<Pointer<Click>>, c_button: Query<NameOrEntity, With<UiLayout>>| {
+                                button_entity.observe(|c_trigger: Trigger<ButtonPressed>, c_button: Query<NameOrEntity, With<UiLayout>>| {
                                     info!("Clicked: {}", c_button.get(c_trigger.target()).unwrap());
                                 });
                             }
@@ -432,6 +635,7 @@ impl NewGameScene {
         asset_server: Res<AssetServer>,
         mut images: ResMut<Assets<Image>>,
         mut materials: ResMut<Assets<StandardMaterial>>,
+        appearance: Res<CharacterAppearance>,
     ) {
 
         // Create the transparent render texture
@@ -451,6 +655,8 @@ impl NewGameScene {
         commands.spawn((
             SceneRoot(asset_server.load("models/person.glb#Scene0")),
             Transform::from_xyz(-0.3, -1.5, -1.0),
+            // Make the model follow the character appearance
+            CharacterModel,
             // A scene marker for later mass scene despawn, not UI related
             NewGameScene
         ));
@@ -497,61 +703,10 @@ impl NewGameScene {
             ));
 
             // Spawn return button
-            ui.spawn((
-                Name::new("Return"),
-                UiLayout::window().pos(Rl((2.0, 4.0))).size(Rl((16.0, 8.0))).pack(),
-                OnHoverSetCursor::new(bevy::window::SystemCursorIcon::Pointer),
-            )).with_children(|ui| {
-                // Spawn the image
-                ui.spawn((
-                    // You can define layouts for multiple states
-                    UiLayout::new(vec![
-                        (UiBase::id(), UiLayout::boundary().pos2(Rl(100.0)).wrap()),
-                        (UiHover::id(), UiLayout::boundary().pos2(Rl(100.0)).x2(Rl(115.0)).wrap())
-                    ]),
-                    // Like this you can enable a state
-                    UiHover::new().forward_speed(20.0).backward_speed(4.0),
-                    // You can specify colors for multiple states
-                    UiColor::new(vec![
-                        (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.15)),
-                        (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
-                    ]),
-                    Sprite {
-                        image: asset_server.load("images/ui/components/button_sliced_bottom_right.png"),
-                        // Here we enable sprite slicing
-                        image_mode: SpriteImageMode::Sliced(TextureSlicer { border: BorderRect::all(32.0), ..default() }),
-                        ..default()
-                    },
-                    // Make sure it does not cover the bounding zone of parent
-                    Pickable::IGNORE,
-                )).with_children(|ui| {
-
-                    // Spawn the text
-                    ui.spawn((
-                        // For text always use window layout to position it
-                        UiLayout::window().pos((Rh(40.0), Rl(50.0))).anchor(Anchor::CenterLeft).pack(),
-                        UiColor::new(vec![
-                            (UiBase::id(), Color::BEVYPUNK_RED),
-                            (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
-                        ]),
-                        UiHover::new().forward_speed(20.0).backward_speed(4.0),
-                        // You can control the size of the text
-                        UiTextSize::from(Rh(60.0)),
-                        // You can attach text like this
-                        Text2d::new("Return"),
-                        TextFont {
-                            font: asset_server.load("fonts/rajdhani/Rajdhani-Medium.ttf"),
-                            font_size: 64.0,
-                            ..default()
-                        },
-                        // Make sure it does not cover the bounding zone of parent
-                        Pickable::IGNORE,
-                    ));
-                });
-
-            // Enable the transition on hover
-            }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>)
-            .observe(|_: Trigger<Pointer<Click>>, mut next: ResMut<NextState<AppState>>| next.set(AppState::MainMenu) );
+            MenuButton::new("Return").image("images/ui/components/button_sliced_bottom_right.png")
+                .layouts(UiLayout::boundary().pos2(Rl(100.0)).wrap(), UiLayout::boundary().pos2(Rl(100.0)).x2(Rl(115.0)).wrap())
+                .spawn(ui, &asset_server, UiLayout::window().pos(Rl((2.0, 4.0))).size(Rl((16.0, 8.0))).pack())
+                .observe(|_: Trigger<ButtonPressed>, mut commands: Commands| commands.trigger(GoBack) );
 
             // Spawn panel boundary
             ui.spawn((
@@ -589,136 +744,47 @@ impl NewGameScene {
                         let gap = 2.0;
                         let size = 15.0;
                         let mut offset = 0.0;
-                        for array in [
-                            ( "Voice tone", (0..16).collect::<Vec<usize>>()),
-                            ( "Skin tone", (0..16).collect()),
-                            ( "Skin type", (0..16).collect()),
-                            ( "Hairstyle", (0..16).collect()),
-                            ( "Hair color", (0..16).collect()),
-                            ( "Eyes", (0..16).collect()),
-                        ] {
-
-                            ui.spawn((
-                                Name::new(array.0),
-                                UiLayout::window().y(Rl(offset)).size(Rl((100.0, size))).pack(),
-                            )).with_children(|ui| {
-
-                                ui.spawn((
-                                    UiLayout::window().size(Rl((100.0, 60.0))).pack(),
-                                )).with_children(|ui| {
-                                    // Spawn the image
-                                    ui.spawn((
-                                        UiLayout::window().full().pack(),
-                                        // Like this you can enable a state
-                                        UiHover::new().forward_speed(20.0).backward_speed(4.0),
-                                        // You can specify colors for multiple states
-                                        UiColor::new(vec![
-                                            (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.15)),
-                                            (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
-                                        ]),
-                                        Sprite {
-                                            image: asset_server.load("images/ui/components/button_symetric_sliced.png"),
-                                            // Here we enable sprite slicing
-                                            image_mode: SpriteImageMode::Sliced(TextureSlicer { border: BorderRect::all(32.0), ..default() }),
-                                            ..default()
-                                        },
-                                        // Make sure it does not cover the bounding zone of parent
-                                        Pickable::IGNORE,
-                                    )).with_children(|ui| {
-
-                                        // Spawn the text
-                                        ui.spawn((
-                                            // For text always use window layout to position it
-                                            UiLayout::window().pos((Rh(40.0), Rl(50.0))).anchor(Anchor::CenterLeft).pack(),
-                                            UiColor::new(vec![
-                                                (UiBase::id(), Color::BEVYPUNK_RED),
-                                                (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
-                                            ]),
-                                            UiHover::new().forward_speed(20.0).backward_speed(4.0),
-                                            // You can control the size of the text
-                                            UiTextSize::from(Rh(60.0)),
-                                            // You can attach text like this
-                                            Text2d::new(array.0.to_uppercase()),
-                                            TextFont {
-                                                font: asset_server.load("fonts/rajdhani/Rajdhani-Medium.ttf"),
-                                                font_size: 64.0,
-                                                ..default()
-                                            },
-                                            // Make sure it does not cover the bounding zone of parent
-                                            Pickable::IGNORE,
-                                        ));
-                                    });
+                        for slot in AppearanceSlot::ALL {
+
+                            // Spawn the selector showing the current option
+                            ChevronSelector::new(slot.name()).options(slot.options()).index(slot.get(&appearance)).vertical()
+                                .spawn(ui, &asset_server, UiLayout::window().y(Rl(offset)).size(Rl((100.0, size))).pack())
+                                .insert(slot)
+                                // Store the choice and let other systems know
+                                .observe(move |trigger: Trigger<SelectorChanged>, mut appearance: ResMut<CharacterAppearance>, mut changed: EventWriter<AppearanceChanged>| {
+                                    slot.set(&mut appearance, trigger.index);
+                                    changed.write(AppearanceChanged { slot, index: trigger.index });
                                 });
 
-                                ui.spawn((
-                                    UiLayout::window().y(Rl(65.0)).size(Rl((48.5, 35.0))).pack(),
-                                    OnHoverSetCursor::new(bevy::window::SystemCursorIcon::Pointer),
-                                )).with_children(|ui| {
-                                    ui.spawn((
-                                        UiLayout::window().full().pack(),
-                                        UiHover::new().instant(true),
-                                        UiColor::new(vec![
-                                            (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.15)),
-                                            (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
-                                        ]),
-                                        Sprite {
-                                            image: asset_server.load("images/ui/components/button_sliced_bottom_left.png"),
-                                            image_mode: SpriteImageMode::Sliced(TextureSlicer { border: BorderRect::all(32.0), ..default() }),
-                                            ..default()
-                                        },
-                                        Pickable::IGNORE,
-                                    )).with_children(|ui| {
-                                        ui.spawn((
-                                            Name::new("Chevron Left"),
-                                            UiLayout::window().pos(Rl((50.0, 50.0))).anchor(Anchor::Center).size(Rh(65.0)).pack(),
-                                            Sprite::from_image(asset_server.load("images/ui/components/chevron_left.png")),
-                                            UiHover::new().forward_speed(20.0).backward_speed(20.0).curve(|v| v.round()),
-                                            UiColor::new(vec![
-                                                (UiBase::id(), Color::BEVYPUNK_RED),
-                                                (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
-                                            ]),
-                                        ));
-                                    });
-                                }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>);
-
-                                ui.spawn((
-                                    UiLayout::window().x(Rl(51.5)).y(Rl(65.0)).size(Rl((48.5, 35.0))).pack(),
-                                    OnHoverSetCursor::new(bevy::window::SystemCursorIcon::Pointer),
-                                )).with_children(|ui| {
-                                    ui.spawn((
-                                        UiLayout::window().full().pack(),
-                                        UiHover::new().instant(true),
-                                        UiColor::new(vec![
-                                            (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.15)),
-                                            (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
-                                        ]),
-                                        Sprite {
-                                            image: asset_server.load("images/ui/components/button_sliced_bottom_right.png"),
-                                            image_mode: SpriteImageMode::Sliced(TextureSlicer { border: BorderRect::all(32.0), ..default() }),
-                                            ..default()
-                                        },
-                                        Pickable::IGNORE,
-                                    )).with_children(|ui| {
-                                        ui.spawn((
-                                            Name::new("Chevron Right"),
-                                            UiLayout::window().pos(Rl((50.0, 50.0))).anchor(Anchor::Center).size(Rh(65.0)).pack(),
-                                            Sprite::from_image(asset_server.load("images/ui/components/chevron_right.png")),
-                                            UiHover::new().forward_speed(20.0).backward_speed(20.0).curve(|v| v.round()),
-                                            UiColor::new(vec![
-                                                (UiBase::id(), Color::BEVYPUNK_RED),
-                                                (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
-                                            ]),
-                                        ));
-                                    });
-                                }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>);
-
-                            });
-
                             offset += gap + size;
                         }
 
                     });
 
+                    // Spawn confirm button
+                    MenuButton::new("Confirm").centered()
+                        .spawn(ui, &asset_server, UiLayout::window().pos(Rl((50.0, 84.0))).anchor(Anchor::TopCenter).size(Rl((60.0, 6.0))).pack())
+                        .observe(|_: Trigger<ButtonPressed>, mut commands: Commands, asset_server: Res<AssetServer>, store: Res<SaveStore>, active: Option<Res<ActiveSave>>, appearance: Res<CharacterAppearance>, mut navigation: ResMut<Navigation>, mut next: ResMut<NextState<AppState>>| {
+                            // Update the resumed save, otherwise create a new slot for the character
+                            let (slot, mut data) = match active {
+                                Some(active) => (active.slot.clone(), active.data.clone()),
+                                None => (store.next_id(), SaveData::new("New Character", *appearance, "NewGame")),
+                            };
+                            data.character = *appearance;
+                            if let Err(error) = store.save(&slot, &data) {
+                                error!("Failed to write save {slot}: {error}");
+                                commands.trigger(UiSound::Error);
+                                let dialog = Dialog::new("Save failed").body(format!("The game could not be saved: {error}")).buttons(["OK"]).cancel_button(0)
+                                    .spawn(&mut commands, &asset_server)
+                                    .insert(NewGameScene)
+                                    .id();
+                                navigation.push_overlay(dialog);
+                                return;
+                            }
+                            commands.insert_resource(ActiveSave { slot, data });
+                            next.set(AppState::MainMenu);
+                        });
+
                 });
 
             });
@@ -753,22 +819,31 @@ impl NewGameScene {
 }
 
 
-#[derive(Component)]
-struct SettingsScene;
-impl SettingsScene {
-    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>, mut images: ResMut<Assets<Image>>) {
+impl AppState {
+    /// States that are stored in the save data
+    const RESUMABLE: [AppState; 1] = [AppState::NewGame];
+    /// State matching the stored name, unknown names start the game creation
+    fn from_save(state: &str) -> Self {
+        AppState::RESUMABLE.into_iter().find(|resumable| format!("{resumable:?}") == state).unwrap_or(AppState::NewGame)
+    }
+    /// Condition for systems that only run while the game is played
+    fn in_gameplay(state: Res<State<AppState>>) -> bool {
+        AppState::RESUMABLE.contains(state.get())
+    }
+}
 
-        // Create the transparent render texture
-        let image_handle = images.add(Image::clear_render_texture());
+/// Make the save active and continue the game from it
+fn resume_save(slot: SaveSlot, commands: &mut Commands, next: &mut NextState<AppState>) {
+    commands.insert_resource(slot.data.character);
+    next.set(AppState::from_save(&slot.data.state));
+    commands.insert_resource(ActiveSave { slot: slot.id, data: slot.data });
+}
 
-        // Create embedd camera that will render to the texture
-        let texture_camera = commands.spawn((
-            Camera2d, Camera::clear_render_to(image_handle.clone()).with_order(-1),
-            // This filters out all the normal entities
-            RenderLayers::layer(1),
-            // A scene marker for later mass scene despawn, not UI related
-            SettingsScene
-        )).id();
+
+#[derive(Component)]
+struct LoadGameScene;
+impl LoadGameScene {
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
 
         // Create UI
         commands.spawn((
@@ -776,7 +851,7 @@ impl SettingsScene {
             // Make the UI synchronized with camera viewport size
             UiFetchFromCamera::<0>,
             // A scene marker for later mass scene despawn, not UI related
-            SettingsScene
+            LoadGameScene
         )).with_children(|ui| {
 
             // Spawn the background
@@ -786,133 +861,735 @@ impl SettingsScene {
                 Sprite::from_image(asset_server.load("images/ui/background.png")),
             ));
 
-            // Spawn the settings content
+            // Spawn return button
+            MenuButton::new("Return").image("images/ui/components/button_sliced_bottom_right.png")
+                .layouts(UiLayout::boundary().pos2(Rl(100.0)).wrap(), UiLayout::boundary().pos2(Rl(100.0)).x2(Rl(115.0)).wrap())
+                .spawn(ui, &asset_server, UiLayout::window().pos(Rl((2.0, 4.0))).size(Rl((16.0, 8.0))).pack())
+                .observe(|_: Trigger<ButtonPressed>, mut commands: Commands| commands.trigger(GoBack) );
+
+            // Spawn the load game content
             ui.spawn((
                 UiLayout::solid().size((3.0, 3.0)).align_y(-1.0).pack(),
             )).with_children(|ui| {
 
-                // Spawn the tab bar
+                // Spawn the title
                 ui.spawn((
-                    UiLayout::window().size(Rl((100.0, 8.0))).pack(),
-                )).with_children(|ui| {
+                    // For text always use window layout to position it
+                    UiLayout::window().pos(Rl((0.0, 4.0))).anchor(Anchor::CenterLeft).pack(),
+                    UiColor::from(Color::BEVYPUNK_RED),
+                    UiTextSize::from(Rh(5.0)),
+                    Text2d::new("LOAD GAME"),
+                    TextFont {
+                        font: asset_server.load("fonts/rajdhani/Rajdhani-SemiBold.ttf"),
+                        font_size: 64.0,
+                        ..default()
+                    },
+                ));
 
-                    // Spawn left chevron
-                    ui.spawn((
-                        Name::new("Chevron Left"),
-                        UiLayout::window().pos(Rl((5.0, 50.0))).anchor(Anchor::Center).size(Rh(35.0)).pack(),
-                        Sprite::from_image(asset_server.load("images/ui/components/chevron_left.png")),
-                        UiHover::new().instant(true),
-                        UiColor::new(vec![
-                            (UiBase::id(), Color::BEVYPUNK_RED),
-                            (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
-                        ]),
-                    )).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>);
-
-                    // Spawn right chevron
-                    ui.spawn((
-                        Name::new("Chevron Right"),
-                        UiLayout::window().pos(Rl((95.0, 50.0))).anchor(Anchor::Center).size(Rh(35.0)).pack(),
-                        Sprite::from_image(asset_server.load("images/ui/components/chevron_right.png")),
-                        UiHover::new().instant(true),
-                        UiColor::new(vec![
-                            (UiBase::id(), Color::BEVYPUNK_RED),
-                            (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
-                        ]),
-                    )).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>);
-
-                    // Spawn the control bar
-                    ui.spawn((
-                        UiLayout::window().x(Rl(10.0)).size(Rl((80.0, 100.0))).pack(),
-                    )).with_children(|ui| {
+                // Spawn the list area, filled with the saves
+                let list = ui.spawn((
+                    Name::new("Save List"),
+                    UiLayout::boundary().y1(Rl(10.0)).pos2(Rl((100.0, 86.0))).pack(),
+                    SaveList,
+                )).observe(SaveList::refresh).id();
+                ui.commands().trigger_targets(RefreshSaveList, list);
+            });
+        });
+    }
+}
 
-                        let categories = ["Controls", "Sound", "Graphics", "Window"];
-                        let pos = 100.0 / categories.len() as f32;
-                        for (i, category) in categories.into_iter().enumerate() {
-
-                            // Spawn the button
-                            ui.spawn((
-                                Name::new(category),
-                                UiLayout::window().x(Rl(pos * i as f32)).size(Rl((pos, 100.0))).pack(),
-                            )).with_children(|ui| {
-
-                                // Spawn the background
-                                ui.spawn((
-                                    UiLayout::window().full().y(Rl(10.0)).height(Rl(80.0)).pack(),
-                                    UiHover::new().forward_speed(20.0).backward_speed(5.0),
-                                    UiColor::new(vec![
-                                        (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.0)),
-                                        (UiHover::id(), Color::BEVYPUNK_RED.with_alpha(0.4))
-                                    ]),
-                                    Sprite {
-                                        image: asset_server.load("images/ui/components/button_symetric_sliced.png"),
-                                        image_mode: SpriteImageMode::Sliced(TextureSlicer { border: BorderRect::all(32.0), ..default() }),
-                                        ..default()
-                                    },
-                                    Pickable::IGNORE,
-                                )).with_children(|ui| {
-
-                                    // Spawn the text
-                                    ui.spawn((
-                                        UiLayout::window().pos(Rl(50.0)).anchor(Anchor::Center).pack(),
-                                        UiColor::new(vec![
-                                            (UiBase::id(), Color::BEVYPUNK_RED),
-                                            (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
-                                        ]),
-                                        UiHover::new().instant(true),
-                                        UiTextSize::from(Rh(50.0)),
-                                        Text2d::new(category.to_ascii_uppercase()),
-                                        TextFont {
-                                            font: asset_server.load("fonts/rajdhani/Rajdhani-Medium.ttf"),
-                                            font_size: 64.0,
-                                            ..default()
-                                        },
-                                        Pickable::IGNORE,
-                                    ));
-                                });
+/// Marker for the entity holding the save entries
+#[derive(Component)]
+struct SaveList;
+impl SaveList {
+    /// Observer for replacing the entries with the saves currently on disk
+    fn refresh(trigger: Trigger<RefreshSaveList>, mut commands: Commands, asset_server: Res<AssetServer>, store: Res<SaveStore>) {
+        let slots = store.slots();
+        commands.entity(trigger.target()).despawn_related::<Children>().with_children(|ui| {
+
+            // Let the player know there is nothing to load
+            if slots.is_empty() {
+                ui.spawn((
+                    UiLayout::window().pos((Rh(40.0), Rl(5.0))).anchor(Anchor::CenterLeft).pack(),
+                    UiColor::from(Color::BEVYPUNK_RED.with_alpha(0.4)),
+                    UiTextSize::from(Rh(4.0)),
+                    Text2d::new("NO SAVED GAMES"),
+                    TextFont {
+                        font: asset_server.load("fonts/rajdhani/Rajdhani-Medium.ttf"),
+                        font_size: 64.0,
+                        ..default()
+                    },
+                ));
+            }
+
+            // Spawn the entries
+            let gap = 2.0;
+            let size = 10.0;
+            let mut offset = 0.0;
+            for slot in slots {
+                SaveEntry::spawn(ui, &asset_server, slot, UiLayout::window().y(Rl(offset)).size(Rl((100.0, size))).pack());
+                offset += gap + size;
+            }
+        });
+    }
+}
 
-                            // Add the observers
-                            }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>);
-                        }
+/// Triggered on the [`SaveList`] to rebuild its entries
+#[derive(Event, Clone, Copy, Debug)]
+struct RefreshSaveList;
 
-                    });
+/// A single save in the [`SaveList`]
+#[derive(Component)]
+struct SaveEntry;
+impl SaveEntry {
+    /// Spawns the entry with load and delete buttons
+    fn spawn(ui: &mut ChildSpawnerCommands, asset_server: &AssetServer, slot: SaveSlot, layout: UiLayout) {
+        let details = format!("{}  |  {}  |  {}", SaveEntry::character(&slot.data.character), SaveEntry::playtime(slot.data.playtime), SaveEntry::date(slot.data.timestamp));
+        ui.spawn((
+            Name::new(format!("Save {}", slot.id)),
+            layout,
+            SaveEntry,
+        )).with_children(|ui| {
 
+            // Spawn the load button
+            let id = slot.id.clone();
+            let name = slot.data.name.clone();
+            MenuButton::new(&name)
+                .spawn(ui, asset_server, UiLayout::window().size(Rl((80.0, 100.0))).pack())
+                .observe(move |_: Trigger<ButtonPressed>, mut commands: Commands, mut next: ResMut<NextState<AppState>>| resume_save(slot.clone(), &mut commands, &mut next) )
+                .with_children(|ui| {
+
+                    // Spawn the character summary, playtime and date
+                    ui.spawn((
+                        // For text always use window layout to position it
+                        UiLayout::window().pos(Rl((97.0, 50.0))).anchor(Anchor::CenterRight).pack(),
+                        UiColor::from(Color::BEVYPUNK_BLUE.with_alpha(0.8)),
+                        UiTextSize::from(Rh(35.0)),
+                        Text2d::new(details),
+                        TextFont {
+                            font: asset_server.load("fonts/rajdhani/Rajdhani-Medium.ttf"),
+                            font_size: 64.0,
+                            ..default()
+                        },
+                        Pickable::IGNORE,
+                    ));
                 });
 
-                // Spawn the Bevy UI embedd
+            // Spawn the delete button
+            MenuButton::new("Delete").centered()
+                .spawn(ui, asset_server, UiLayout::window().x(Rl(82.0)).size(Rl((18.0, 100.0))).pack())
+                .observe(move |_: Trigger<ButtonPressed>, mut commands: Commands, asset_server: Res<AssetServer>, mut navigation: ResMut<Navigation>| {
+                    let dialog = SaveEntry::delete_dialog(&mut commands, &asset_server, id.clone(), &name);
+                    navigation.push_overlay(dialog);
+                });
+        });
+    }
+    /// Spawns the dialog confirming the deletion of the slot
+    fn delete_dialog(commands: &mut Commands, asset_server: &AssetServer, slot: String, name: &str) -> Entity {
+        Dialog::new("Delete save").body(format!("{name} will be lost forever.")).buttons(["Delete", "Cancel"]).default_button(1).cancel_button(1)
+            .spawn(commands, asset_server)
+            .insert(LoadGameScene)
+            .observe(move |trigger: Trigger<DialogChosen>, mut commands: Commands, store: Res<SaveStore>, list: Single<Entity, With<SaveList>>| {
+                if trigger.0 != 0 { return; }
+                if let Err(error) = store.delete(&slot) {
+                    error!("Failed to delete save {slot}: {error}");
+                    commands.trigger(UiSound::Error);
+                }
+                commands.trigger_targets(RefreshSaveList, *list);
+            }).id()
+    }
+    /// Short description of the character
+    fn character(appearance: &CharacterAppearance) -> String {
+        let option = |slot: AppearanceSlot| slot.options()[slot.get(appearance).min(slot.options().len() - 1)].to_ascii_uppercase();
+        format!("{} SKIN, {} HAIR", option(AppearanceSlot::SkinTone), option(AppearanceSlot::HairColor))
+    }
+    /// Playtime as hours and minutes
+    fn playtime(playtime: Duration) -> String {
+        let minutes = playtime.as_secs() / 60;
+        format!("{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+    /// Date of the save in UTC
+    fn date(timestamp: u64) -> String {
+        // Convert days since the epoch into a civil date
+        let days = (timestamp / 86400) as i64 + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days.rem_euclid(146097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        let seconds = timestamp % 86400;
+        format!("{year}-{month:02}-{day:02} {:02}:{:02}", seconds / 3600, seconds / 60 % 60)
+    }
+}
+
+
+#[derive(Component)]
+struct SettingsScene;
+impl SettingsScene {
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+
+        // Create UI
+        commands.spawn((
+            UiLayoutRoot::new_2d(),
+            // Make the UI synchronized with camera viewport size
+            UiFetchFromCamera::<0>,
+            // A scene marker for later mass scene despawn, not UI related
+            SettingsScene
+        )).with_children(|ui| {
+
+            // Spawn the background
+            ui.spawn((
+                Name::new("Background"),
+                UiLayout::solid().size((1920.0, 1080.0)).scaling(Scaling::Fill).pack(),
+                Sprite::from_image(asset_server.load("images/ui/background.png")),
+            ));
+
+            // Spawn the settings content
+            ui.spawn((
+                UiLayout::solid().size((3.0, 3.0)).align_y(-1.0).pack(),
+            )).with_children(|ui| {
+
+                // Spawn the tab bar
+                TabBar::new(SettingsTab::ALL.map(SettingsTab::name))
+                    .spawn(ui, &asset_server, UiLayout::window().size(Rl((100.0, 8.0))).pack())
+                    .observe(|trigger: Trigger<TabSelected>, mut next: ResMut<NextState<SettingsTab>>| next.set(SettingsTab::ALL[trigger.0]) );
+
+                // Spawn the page area, filled by the selected tab
                 ui.spawn((
+                    Name::new("Page"),
                     UiLayout::boundary().y1(Rl(10.0)).pos2(Rl(100.0)).pack(),
-                    Sprite::from_image(image_handle),
-                    UiEmbedding,
+                    SettingsPage,
                 ));
 
             });
         });
+    }
+}
 
-        // The Bevy UI nodes must be here to work
-        commands.spawn((
-            Node {
-                width: Val::Percent(100.),
-                height: Val::Percent(100.),
-                flex_direction: FlexDirection::Column,
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
+/// Preferences as they were when the settings screen was entered, used to discard the changes
+#[derive(Resource)]
+struct SettingsSnapshot(GamePreferences);
+impl SettingsSnapshot {
+    /// Remember the preferences when entering the screen
+    fn take(mut commands: Commands, preferences: Res<GamePreferences>) {
+        commands.insert_resource(SettingsSnapshot(preferences.clone()));
+    }
+    /// Forget the preferences when leaving the screen
+    fn clear(mut commands: Commands) {
+        commands.remove_resource::<SettingsSnapshot>();
+    }
+    /// Spawns the dialog asking whether to keep the changes before leaving
+    fn dialog(commands: &mut Commands, asset_server: &AssetServer) -> Entity {
+        Dialog::new("Unsaved changes").body("Keep the changed settings?").buttons(["Keep", "Discard", "Cancel"]).default_button(0).cancel_button(2)
+            .spawn(commands, asset_server)
+            .insert(SettingsScene)
+            .observe(|trigger: Trigger<DialogChosen>, mut commands: Commands, mut preferences: ResMut<GamePreferences>, mut snapshot: ResMut<SettingsSnapshot>, pending: Option<Res<PendingWindowPreferences>>| {
+                match trigger.0 {
+                    0 => {
+                        // Keeping also confirms the window changes
+                        if pending.is_some() { commands.trigger(KeepWindowPreferences); }
+                        snapshot.0 = preferences.clone();
+                    },
+                    1 => {
+                        // Discarding restores the window as it was without asking to confirm it again
+                        *preferences = snapshot.0.clone();
+                        commands.trigger(ConfirmWindowPreferences);
+                    },
+                    _ => return,
+                }
+                // Leave the screen now that nothing differs
+                commands.trigger(GoBack);
+            }).id()
+    }
+}
+
+/// Tabs of the settings screen
+#[derive(SubStates, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[source(AppState = AppState::Settings)]
+enum SettingsTab {
+    #[default]
+    Controls,
+    Sound,
+    Graphics,
+    Window,
+}
+impl SettingsTab {
+    /// All tabs in the order they are displayed
+    const ALL: [SettingsTab; 4] = [SettingsTab::Controls, SettingsTab::Sound, SettingsTab::Graphics, SettingsTab::Window];
+    /// Display name of the tab
+    fn name(self) -> &'static str {
+        match self {
+            SettingsTab::Controls => "Controls",
+            SettingsTab::Sound => "Sound",
+            SettingsTab::Graphics => "Graphics",
+            SettingsTab::Window => "Window",
+        }
+    }
+    /// Position of the tab in the tab bar
+    fn index(self) -> usize {
+        SettingsTab::ALL.iter().position(|tab| *tab == self).unwrap_or(0)
+    }
+    /// Options displayed on the page of this tab
+    fn options(self) -> &'static [SettingsOption] {
+        match self {
+            SettingsTab::Controls => &[SettingsOption::MouseSensitivity, SettingsOption::InvertY],
+            SettingsTab::Sound => &[SettingsOption::MasterVolume, SettingsOption::MusicVolume, SettingsOption::SfxVolume, SettingsOption::UiVolume, SettingsOption::VoiceVolume, SettingsOption::Subtitles, SettingsOption::SubtitleSize],
+            SettingsTab::Graphics => &[SettingsOption::Gpu, SettingsOption::LowRam, SettingsOption::SkipIntro],
+            SettingsTab::Window => &[SettingsOption::DisplayMode, SettingsOption::Resolution, SettingsOption::Vsync, SettingsOption::Monitor],
+        }
+    }
+    /// Returns the tab `step` positions away, wrapping around
+    fn cycle(self, step: isize) -> Self {
+        SettingsTab::ALL[cycle_index(self.index(), SettingsTab::ALL.len(), step)]
+    }
+    /// System for switching the tabs with keyboard shortcuts
+    fn keyboard(keys: Res<ButtonInput<KeyCode>>, tab: Res<State<SettingsTab>>, mut next: ResMut<NextState<SettingsTab>>) {
+        if keys.just_pressed(KeyCode::KeyQ) { next.set(tab.cycle(-1)); }
+        if keys.just_pressed(KeyCode::KeyE) { next.set(tab.cycle(1)); }
+        for (key, tab) in [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4].into_iter().zip(SettingsTab::ALL) {
+            if keys.just_pressed(key) { next.set(tab); }
+        }
+    }
+    /// System for keeping the tab bar in sync with the selected tab
+    fn highlight(selected: Res<State<SettingsTab>>, mut query: Query<&mut TabBar>) {
+        for mut bar in &mut query {
+            if bar.selected != selected.index() { bar.selected = selected.index(); }
+        }
+    }
+}
+
+/// Marker for the entity holding the page of the selected tab
+#[derive(Component)]
+struct SettingsPage;
+impl SettingsPage {
+    /// System for replacing the page content when the selected tab changes
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>, preferences: Res<GamePreferences>, tab: Res<State<SettingsTab>>, page: Single<Entity, With<SettingsPage>>) {
+        commands.entity(*page).despawn_related::<Children>().with_children(|ui| {
+
+            // Spawn the option rows
+            let gap = 2.0;
+            let size = 8.0;
+            let mut offset = gap;
+            for option in tab.options() {
+                option.spawn(ui, &asset_server, &preferences, UiLayout::window().y(Rl(offset)).size(Rl((100.0, size))).pack());
+                offset += gap + size;
+            }
+
+            // Spawn the confirmation prompt for window changes
+            if *tab.get() == SettingsTab::Window {
+                WindowConfirmPrompt::spawn(ui, &asset_server, UiLayout::window().y(Rl(offset + gap)).size(Rl((100.0, size))).pack());
+            }
+        });
+    }
+}
+
+/// Every option that can be changed on the settings screen
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingsOption {
+    MouseSensitivity,
+    InvertY,
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    UiVolume,
+    VoiceVolume,
+    Subtitles,
+    SubtitleSize,
+    Gpu,
+    LowRam,
+    SkipIntro,
+    DisplayMode,
+    Resolution,
+    Vsync,
+    Monitor,
+}
+impl SettingsOption {
+    /// Resolutions offered in the window tab
+    const RESOLUTIONS: [(u32, u32); 6] = [(1280, 720), (1366, 768), (1600, 900), (1920, 1080), (2560, 1440), (3840, 2160)];
+    /// Display name of the option
+    fn name(self) -> &'static str {
+        match self {
+            SettingsOption::MouseSensitivity => "Mouse sensitivity",
+            SettingsOption::InvertY => "Invert camera",
+            SettingsOption::MasterVolume => "Master volume",
+            SettingsOption::MusicVolume => "Music volume",
+            SettingsOption::SfxVolume => "Effects volume",
+            SettingsOption::UiVolume => "Interface volume",
+            SettingsOption::VoiceVolume => "Voice volume",
+            SettingsOption::Subtitles => "Subtitles",
+            SettingsOption::SubtitleSize => "Subtitle size",
+            SettingsOption::Gpu => "GPU (restart)",
+            SettingsOption::LowRam => "Low memory (restart)",
+            SettingsOption::SkipIntro => "Skip intro",
+            SettingsOption::DisplayMode => "Display mode",
+            SettingsOption::Resolution => "Resolution",
+            SettingsOption::Vsync => "Vsync",
+            SettingsOption::Monitor => "Monitor",
+        }
+    }
+    /// Value of the options displayed as [`Toggle`]
+    fn flag(self, preferences: &GamePreferences) -> Option<bool> {
+        match self {
+            SettingsOption::InvertY => Some(preferences.controls.invert_y),
+            SettingsOption::LowRam => Some(preferences.graphics.lowram),
+            SettingsOption::SkipIntro => Some(preferences.skip_intro),
+            SettingsOption::Subtitles => Some(preferences.subtitles.enabled),
+            SettingsOption::Vsync => Some(preferences.window.vsync),
+            _ => None,
+        }
+    }
+    /// Set the value of the options displayed as [`Toggle`]
+    fn set_flag(self, preferences: &mut GamePreferences, value: bool) {
+        match self {
+            SettingsOption::InvertY => preferences.controls.invert_y = value,
+            SettingsOption::LowRam => preferences.graphics.lowram = value,
+            SettingsOption::SkipIntro => preferences.skip_intro = value,
+            SettingsOption::Subtitles => preferences.subtitles.enabled = value,
+            SettingsOption::Vsync => preferences.window.vsync = value,
+            _ => {},
+        }
+    }
+    /// Value of the options displayed as [`Slider`]
+    fn volume(self, preferences: &GamePreferences) -> Option<f32> {
+        match self {
+            SettingsOption::MasterVolume => Some(preferences.audio.master),
+            SettingsOption::MusicVolume => Some(preferences.audio.music),
+            SettingsOption::SfxVolume => Some(preferences.audio.sfx),
+            SettingsOption::UiVolume => Some(preferences.audio.ui),
+            SettingsOption::VoiceVolume => Some(preferences.audio.voice),
+            _ => None,
+        }
+    }
+    /// Set the value of the options displayed as [`Slider`]
+    fn set_volume(self, preferences: &mut GamePreferences, value: f32) {
+        match self {
+            SettingsOption::MasterVolume => preferences.audio.master = value,
+            SettingsOption::MusicVolume => preferences.audio.music = value,
+            SettingsOption::SfxVolume => preferences.audio.sfx = value,
+            SettingsOption::UiVolume => preferences.audio.ui = value,
+            SettingsOption::VoiceVolume => preferences.audio.voice = value,
+            _ => {},
+        }
+    }
+    /// Display value of the options displayed as [`ChevronSelector`]
+    fn value(self, preferences: &GamePreferences) -> String {
+        match self {
+            SettingsOption::MouseSensitivity => format!("{:.2}", preferences.controls.mouse_sensitivity),
+            SettingsOption::Gpu => match preferences.graphics.gpu {
+                GpuPower::HighPerformance => "PERFORMANCE".to_string(),
+                GpuPower::LowPower => "POWER SAVER".to_string(),
             },
-            // Render this ui to our new camera
-            UiTargetCamera(texture_camera),
-            // A scene marker for later mass scene despawn, not UI related
-            SettingsScene
-        )).with_children(|parent| {
-            parent.spawn((
-                Text::new("This is a Bevy UI"),
+            SettingsOption::SubtitleSize => match preferences.subtitles.size {
+                SubtitleSize::Small => "SMALL".to_string(),
+                SubtitleSize::Medium => "MEDIUM".to_string(),
+                SubtitleSize::Large => "LARGE".to_string(),
+            },
+            SettingsOption::DisplayMode => match preferences.window.mode {
+                DisplayMode::Windowed => "WINDOWED".to_string(),
+                DisplayMode::Borderless => "BORDERLESS".to_string(),
+                DisplayMode::Fullscreen => "FULLSCREEN".to_string(),
+            },
+            SettingsOption::Resolution => format!("{}x{}", preferences.window.resolution.0, preferences.window.resolution.1),
+            SettingsOption::Monitor => match preferences.window.monitor {
+                Some(index) => format!("MONITOR {}", index + 1),
+                None => "CURRENT".to_string(),
+            },
+            _ => String::new(),
+        }
+    }
+    /// Change the value of the options displayed as [`ChevronSelector`] `step` positions away, wrapping around for lists
+    fn cycle(self, preferences: &mut GamePreferences, step: isize, monitors: usize) {
+        match self {
+            SettingsOption::MouseSensitivity => {
+                let sensitivity = &mut preferences.controls.mouse_sensitivity;
+                *sensitivity = (*sensitivity + 0.02 * step as f32).clamp(0.02, 0.5);
+            },
+            SettingsOption::Gpu => {
+                preferences.graphics.gpu = match preferences.graphics.gpu {
+                    GpuPower::HighPerformance => GpuPower::LowPower,
+                    GpuPower::LowPower => GpuPower::HighPerformance,
+                };
+            },
+            SettingsOption::SubtitleSize => {
+                let sizes = [SubtitleSize::Small, SubtitleSize::Medium, SubtitleSize::Large];
+                let index = sizes.iter().position(|size| *size == preferences.subtitles.size).unwrap_or(0);
+                preferences.subtitles.size = sizes[cycle_index(index, sizes.len(), step)];
+            },
+            SettingsOption::DisplayMode => {
+                let modes = [DisplayMode::Windowed, DisplayMode::Borderless, DisplayMode::Fullscreen];
+                let index = modes.iter().position(|mode| *mode == preferences.window.mode).unwrap_or(0);
+                preferences.window.mode = modes[cycle_index(index, modes.len(), step)];
+            },
+            SettingsOption::Resolution => {
+                let resolutions = SettingsOption::RESOLUTIONS;
+                let index = resolutions.iter().position(|resolution| *resolution == preferences.window.resolution).unwrap_or(0);
+                preferences.window.resolution = resolutions[cycle_index(index, resolutions.len(), step)];
+            },
+            SettingsOption::Monitor => {
+                // Index 0 is the current monitor, the rest are the connected ones
+                let index = preferences.window.monitor.map_or(0, |index| index + 1);
+                let index = cycle_index(index, monitors + 1, step);
+                preferences.window.monitor = index.checked_sub(1);
+            },
+            _ => {},
+        }
+    }
+    /// Spawns the widget matching the option type
+    fn spawn(self, ui: &mut ChildSpawnerCommands, asset_server: &AssetServer, preferences: &GamePreferences, layout: UiLayout) {
+        if let Some(value) = self.flag(preferences) {
+            Toggle::new(self.name(), value).spawn(ui, asset_server, layout).insert(self)
+                .observe(move |trigger: Trigger<ToggleChanged>, mut preferences: ResMut<GamePreferences>| self.set_flag(&mut preferences, trigger.0) );
+        } else if let Some(value) = self.volume(preferences) {
+            Slider::new(self.name(), value).spawn(ui, asset_server, layout).insert(self)
+                .observe(move |trigger: Trigger<SliderChanged>, mut preferences: ResMut<GamePreferences>| self.set_volume(&mut preferences, trigger.0) );
+        } else {
+            ChevronSelector::new(self.name()).text(self.value(preferences)).spawn(ui, asset_server, layout).insert(self)
+                .observe(move |trigger: Trigger<SelectorChanged>, mut preferences: ResMut<GamePreferences>, monitors: Query<(), With<bevy::window::Monitor>>| {
+                    self.cycle(&mut preferences, trigger.step, monitors.iter().len());
+                });
+        }
+    }
+    /// System for updating the widgets when the preferences change
+    fn system(
+        preferences: Res<GamePreferences>,
+        mut selectors: Query<(&SettingsOption, &mut ChevronSelector)>,
+        mut toggles: Query<(&SettingsOption, &mut Toggle)>,
+        mut sliders: Query<(&SettingsOption, &mut Slider)>,
+    ) {
+        for (option, mut selector) in &mut selectors {
+            let text = option.value(&preferences);
+            if selector.text != text { selector.text = text; }
+        }
+        for (option, mut toggle) in &mut toggles {
+            if let Some(value) = option.flag(&preferences) {
+                if toggle.value != value { toggle.value = value; }
+            }
+        }
+        for (option, mut slider) in &mut sliders {
+            if let Some(value) = option.volume(&preferences) {
+                if slider.value != value { slider.value = value; }
+            }
+        }
+    }
+}
+
+/// The "keep these settings?" prompt shown while window changes await confirmation
+#[derive(Component)]
+struct WindowConfirmPrompt;
+impl WindowConfirmPrompt {
+    /// Spawns the hidden prompt with keep and revert buttons
+    fn spawn(ui: &mut ChildSpawnerCommands, asset_server: &AssetServer, layout: UiLayout) {
+        ui.spawn((
+            Name::new("Window Confirm Prompt"),
+            layout,
+            Visibility::Hidden,
+            WindowConfirmPrompt,
+        )).with_children(|ui| {
+
+            // Spawn the countdown text
+            ui.spawn((
+                UiLayout::window().pos((Rh(40.0), Rl(50.0))).anchor(Anchor::CenterLeft).pack(),
+                UiColor::from(Color::BEVYPUNK_YELLOW),
+                UiTextSize::from(Rh(50.0)),
+                Text2d::default(),
                 TextFont {
+                    font: asset_server.load("fonts/rajdhani/Rajdhani-SemiBold.ttf"),
                     font_size: 64.0,
-                    font: asset_server.load("fonts/rajdhani/Rajdhani-Medium.ttf"),
                     ..default()
                 },
-                TextColor::WHITE,
+                Pickable::IGNORE,
+                WindowConfirmCountdown,
+            ));
+
+            // Spawn the buttons
+            MenuButton::new("Keep").centered()
+                .spawn(ui, asset_server, UiLayout::window().x(Rl(57.0)).size(Rl((21.0, 100.0))).pack())
+                .observe(|_: Trigger<ButtonPressed>, mut commands: Commands| commands.trigger(KeepWindowPreferences) );
+            MenuButton::new("Revert").centered()
+                .spawn(ui, asset_server, UiLayout::window().x(Rl(79.0)).size(Rl((21.0, 100.0))).pack())
+                .observe(|_: Trigger<ButtonPressed>, mut commands: Commands| commands.trigger(RevertWindowPreferences) );
+        });
+    }
+    /// System for showing the prompt and counting down while a window change is pending
+    fn system(
+        mut commands: Commands,
+        pending: Option<Res<PendingWindowPreferences>>,
+        mut prompts: Query<&mut Visibility, With<WindowConfirmPrompt>>,
+        mut texts: Query<&mut Text2d, With<WindowConfirmCountdown>>,
+    ) {
+        for mut visibility in &mut prompts {
+            visibility.set_if_neq(if pending.is_some() { Visibility::Inherited } else { Visibility::Hidden });
+        }
+        if let Some(pending) = pending {
+            for mut text in &mut texts {
+                text.0 = format!("KEEP THESE SETTINGS? {}", pending.remaining_secs().ceil());
+            }
+            commands.trigger(RecomputeUiLayout);
+        }
+    }
+}
+
+/// Marker for the countdown text of the [`WindowConfirmPrompt`]
+#[derive(Component)]
+struct WindowConfirmCountdown;
+
+
+#[derive(Component)]
+struct CreditsScene;
+impl CreditsScene {
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+
+        // Create UI
+        commands.spawn((
+            UiLayoutRoot::new_2d(),
+            // Make the UI synchronized with camera viewport size
+            UiFetchFromCamera::<0>,
+            // A scene marker for later mass scene despawn, not UI related
+            CreditsScene
+        )).with_children(|ui| {
+
+            // Spawn the background
+            ui.spawn((
+                Name::new("Background"),
+                UiLayout::solid().size((1920.0, 1080.0)).scaling(Scaling::Fill).pack(),
+                Sprite::from_image(asset_server.load("images/ui/background.png")),
             ));
+
+            // Spawn the roll, filled once the credits are loaded
+            ui.spawn((
+                Name::new("Credits Roll"),
+                UiLayout::window().y(Rl(100.0)).size(Rl(100.0)).pack(),
+                CreditsRoll::new(asset_server.load("bevypunk.credits.ron")),
+            ));
+
+            // Spawn skip button
+            MenuButton::new("Skip").image("images/ui/components/button_sliced_bottom_right.png")
+                .layouts(UiLayout::boundary().pos2(Rl(100.0)).wrap(), UiLayout::boundary().pos2(Rl(100.0)).x2(Rl(115.0)).wrap())
+                .spawn(ui, &asset_server, UiLayout::window().pos(Rl((2.0, 4.0))).size(Rl((16.0, 8.0))).pack())
+                .observe(|_: Trigger<ButtonPressed>, mut commands: Commands| commands.trigger(GoBack) );
         });
+    }
+}
+
+/// Scrolling container of the credit lines
+#[derive(Component)]
+struct CreditsRoll {
+    credits: Handle<Credits>,
+    /// Vertical position of the roll in percent of the screen height
+    offset: f32,
+    /// Height of all lines in percent of the screen height
+    length: f32,
+    built: bool,
+}
+impl CreditsRoll {
+    /// Scroll speed in percent of the screen height per second
+    const SPEED: f32 = 7.0;
+    /// Creates new instance starting below the screen
+    fn new(credits: Handle<Credits>) -> Self {
+        Self {
+            credits,
+            offset: 100.0,
+            length: 0.0,
+            built: false,
+        }
+    }
+    /// Spawns a line waiting below the screen, returns its height
+    fn spawn_line(ui: &mut ChildSpawnerCommands, asset_server: &AssetServer, y: f32, line: CreditsLine, size: f32, color: Color, font: &str) -> f32 {
+        ui.spawn((
+            // For text always use window layout to position it
+            UiLayout::window().pos(Rl((50.0, y))).anchor(Anchor::TopCenter).pack(),
+            UiColor::from(color),
+            UiTextSize::from(Rh(size)),
+            Text2d::default(),
+            TextFont {
+                font: asset_server.load(font.to_string()),
+                font_size: 64.0,
+                ..default()
+            },
+            line,
+        ));
+        size * 1.3
+    }
+    /// Spawns all lines of the credits, returns the total height
+    fn build(ui: &mut ChildSpawnerCommands, asset_server: &AssetServer, credits: &Credits) -> f32 {
+        let mut y = 0.0;
+        for section in &credits.sections {
+            y += CreditsRoll::spawn_line(ui, asset_server, y, CreditsLine::new(section.title.to_ascii_uppercase(), y, decryption_animation), 6.0, Color::BEVYPUNK_RED, "fonts/rajdhani/Rajdhani-Bold.ttf") + 2.0;
+            for entry in &section.entries {
+                y += CreditsRoll::spawn_line(ui, asset_server, y, CreditsLine::new(entry.role.to_ascii_uppercase(), y, typing_animation), 2.5, Color::BEVYPUNK_RED.with_alpha(0.6), "fonts/rajdhani/Rajdhani-Medium.ttf");
+                for name in &entry.names {
+                    y += CreditsRoll::spawn_line(ui, asset_server, y, CreditsLine::new(name, y, decryption_animation), 3.5, Color::BEVYPUNK_BLUE, "fonts/rajdhani/Rajdhani-SemiBold.ttf");
+                }
+                y += 2.0;
+            }
+            y += 10.0;
+        }
+        for license in &credits.licenses {
+            y += CreditsRoll::spawn_line(ui, asset_server, y, CreditsLine::new(format!("{} LICENSE", license.title.to_ascii_uppercase()), y, decryption_animation), 4.0, Color::BEVYPUNK_RED, "fonts/rajdhani/Rajdhani-Bold.ttf") + 2.0;
+            for text in license.text.lines() {
+                y += CreditsRoll::spawn_line(ui, asset_server, y, CreditsLine::new(text.trim_end(), y, typing_animation), 1.8, Color::BEVYPUNK_RED.with_alpha(0.5), "fonts/rajdhani/Rajdhani-Medium.ttf");
+            }
+            y += 10.0;
+        }
+        y
+    }
+    /// System for building, scrolling and ending the roll
+    fn system(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        assets: Res<Assets<Credits>>,
+        time: Res<Time>,
+        mut rolls: Query<(Entity, &mut CreditsRoll, &mut UiLayout)>,
+        lines: Query<(Entity, &CreditsLine)>,
+        mut next: ResMut<NextState<AppState>>,
+    ) {
+        for (entity, mut roll, mut layout) in &mut rolls {
+
+            // Wait for the credits to load
+            if !roll.built {
+                let Some(credits) = assets.get(&roll.credits) else { continue };
+                let mut length = 0.0;
+                commands.entity(entity).with_children(|ui| length = CreditsRoll::build(ui, &asset_server, credits));
+                roll.length = length;
+                roll.built = true;
+                continue;
+            }
+
+            // Scroll the roll upwards
+            roll.offset -= CreditsRoll::SPEED * time.delta_secs();
+            *layout = UiLayout::window().y(Rl(roll.offset)).size(Rl(100.0)).pack();
+
+            // Reveal the lines entering the screen
+            for (line_entity, line) in &lines {
+                if line.y + roll.offset > 100.0 { continue; }
+                commands.entity(line_entity).remove::<CreditsLine>()
+                    .insert(TextAnimator::new(&line.text).function(line.function).duration((line.text.len() as f32 * 0.02).clamp(0.2, 1.0)));
+            }
+
+            // Return to the menu once everything scrolled past
+            if roll.offset + roll.length < 0.0 { next.set(AppState::MainMenu); }
+        }
+    }
+}
 
+/// Credit line waiting to be revealed once it scrolls into view
+#[derive(Component)]
+struct CreditsLine {
+    text: String,
+    /// Position of the line on the roll in percent of the screen height
+    y: f32,
+    function: fn(t: f32, text: &str) -> String,
+}
+impl CreditsLine {
+    /// Creates new instance
+    fn new(text: impl std::fmt::Display, y: f32, function: fn(t: f32, text: &str) -> String) -> Self {
+        Self {
+            text: text.to_string(),
+            y,
+            function,
+        }
     }
 }
@@ -0,0 +1,196 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy::prelude::*;
+use bevy_kira_audio::{prelude::*, AudioApp};
+
+
+/// Audio channel for the background music
+#[derive(Resource)]
+pub struct MusicChannel;
+
+/// Audio channel for the sound effects of the world
+#[derive(Resource)]
+pub struct SfxChannel;
+
+/// Audio channel for the sounds of the user interface
+#[derive(Resource)]
+pub struct UiChannel;
+
+/// Audio channel for dialogue and cinematics
+#[derive(Resource)]
+pub struct VoiceChannel;
+
+/// Convert linear amplitude in range `0.0..=1.0` into decibels
+pub fn decibels(amplitude: f32) -> Decibels {
+    if amplitude <= 0.0 { return Decibels::SILENCE; }
+    Decibels((20.0 * amplitude.log10()).max(Decibels::SILENCE.0))
+}
+
+/// Linear volumes of the audio channels in range `0.0..=1.0`.
+/// The master volume scales every channel, including the default [`Audio`] channel.
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct ChannelVolumes {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+    pub ui: f32,
+    pub voice: f32,
+}
+impl Default for ChannelVolumes {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            music: 1.0,
+            sfx: 1.0,
+            ui: 1.0,
+            voice: 1.0,
+        }
+    }
+}
+impl ChannelVolumes {
+    /// System for applying the volumes to the channels
+    fn system(
+        volumes: Res<ChannelVolumes>,
+        main: Res<Audio>,
+        music: Res<AudioChannel<MusicChannel>>,
+        sfx: Res<AudioChannel<SfxChannel>>,
+        ui: Res<AudioChannel<UiChannel>>,
+        voice: Res<AudioChannel<VoiceChannel>>,
+    ) {
+        main.set_volume(decibels(volumes.master));
+        music.set_volume(decibels(volumes.master * volumes.music));
+        sfx.set_volume(decibels(volumes.master * volumes.sfx));
+        ui.set_volume(decibels(volumes.master * volumes.ui));
+        voice.set_volume(decibels(volumes.master * volumes.voice));
+    }
+}
+
+
+/// Lowers the music volume while any entity with this component exists
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+pub struct DucksMusic;
+
+/// Plays music on the [`MusicChannel`] based on the current state `S`.
+/// Tracks are crossfaded on transitions, a track shared by both states keeps playing
+/// and a track that comes back continues from where it faded out.
+#[derive(Resource, Clone)]
+pub struct MusicDirector<S: States> {
+    /// Track of each state, `None` for silence. States without entry keep the current music.
+    tracks: HashMap<S, Option<String>>,
+    /// Tween of the incoming track
+    pub fade_in: AudioTween,
+    /// Tween of the outgoing track
+    pub fade_out: AudioTween,
+    /// Music amplitude while ducked in range `0.0..=1.0`
+    pub duck: f32,
+    /// Tween used for ducking and restoring the music
+    pub duck_tween: AudioTween,
+    /// Path and instance of the playing track
+    current: Option<(String, Handle<AudioInstance>)>,
+    /// Positions in seconds where the faded out tracks stopped
+    positions: HashMap<String, f64>,
+    /// If the music is currently ducked
+    ducked: bool,
+}
+impl<S: States> Default for MusicDirector<S> {
+    fn default() -> Self {
+        Self {
+            tracks: HashMap::new(),
+            fade_in: AudioTween::new(Duration::from_secs(2), AudioEasing::OutPowf(2.0)),
+            fade_out: AudioTween::new(Duration::from_secs(1), AudioEasing::InPowf(2.0)),
+            duck: 0.2,
+            duck_tween: AudioTween::linear(Duration::from_millis(500)),
+            current: None,
+            positions: HashMap::new(),
+            ducked: false,
+        }
+    }
+}
+impl<S: States> MusicDirector<S> {
+    /// Creates new instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Play the track while in the state.
+    pub fn track(mut self, state: S, path: impl std::fmt::Display) -> Self {
+        self.tracks.insert(state, Some(path.to_string()));
+        self
+    }
+    /// Play no music while in the state.
+    pub fn silence(mut self, state: S) -> Self {
+        self.tracks.insert(state, None);
+        self
+    }
+    /// Replace the default crossfade tweens with new ones.
+    pub fn crossfade(mut self, fade_in: AudioTween, fade_out: AudioTween) -> Self {
+        self.fade_in = fade_in;
+        self.fade_out = fade_out;
+        self
+    }
+    /// Replace the default ducked amplitude and its tween with new ones.
+    pub fn ducking(mut self, duck: f32, tween: AudioTween) -> Self {
+        self.duck = duck;
+        self.duck_tween = tween;
+        self
+    }
+    /// System for crossfading to the track of the new state
+    fn system(
+        mut director: ResMut<MusicDirector<S>>,
+        state: Res<State<S>>,
+        asset_server: Res<AssetServer>,
+        music: Res<AudioChannel<MusicChannel>>,
+        mut instances: ResMut<Assets<AudioInstance>>,
+    ) {
+        let Some(track) = director.tracks.get(state.get()).cloned() else { return };
+        if director.current.as_ref().map(|(path, _)| path) == track.as_ref() { return; }
+
+        // Fade out the current track, remembering where it stopped
+        if let Some((path, handle)) = director.current.take() {
+            if let Some(instance) = instances.get_mut(&handle) {
+                if let PlaybackState::Playing { position } = instance.state() { director.positions.insert(path, position); }
+                let _ = instance.stop(director.fade_out.clone());
+            }
+        }
+
+        // Fade in the new track from where it was left
+        if let Some(path) = track {
+            let position = director.positions.get(&path).copied().unwrap_or(0.0);
+            let volume = decibels(if director.ducked { director.duck } else { 1.0 });
+            let handle = music.play(asset_server.load(path.clone())).looped().start_from(position).with_volume(volume).fade_in(director.fade_in.clone()).handle();
+            director.current = Some((path, handle));
+        }
+    }
+    /// System for ducking the music while any [`DucksMusic`] exists
+    fn duck(mut director: ResMut<MusicDirector<S>>, duckers: Query<(), With<DucksMusic>>, mut instances: ResMut<Assets<AudioInstance>>) {
+        let ducked = !duckers.is_empty();
+        if director.ducked == ducked { return; }
+        director.ducked = ducked;
+        let Some((_, handle)) = &director.current else { return };
+        if let Some(instance) = instances.get_mut(handle) {
+            let _ = instance.set_volume(decibels(if ducked { director.duck } else { 1.0 }), director.duck_tween.clone());
+        }
+    }
+}
+
+/// Plugin inserting the [`MusicDirector`] for state `S`, requires [`MixerPlugin`]
+pub struct MusicPlugin<S: States>(pub MusicDirector<S>);
+impl<S: States> Plugin for MusicPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.0.clone());
+        app.add_systems(Update, (MusicDirector::<S>::system.run_if(state_changed::<S>), MusicDirector::<S>::duck).chain());
+    }
+}
+
+
+/// Plugin adding the audio channels and their volumes, requires [`AudioPlugin`]
+pub struct MixerPlugin;
+impl Plugin for MixerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_channel::<MusicChannel>();
+        app.add_audio_channel::<SfxChannel>();
+        app.add_audio_channel::<UiChannel>();
+        app.add_audio_channel::<VoiceChannel>();
+        app.init_resource::<ChannelVolumes>();
+        app.add_systems(Update, ChannelVolumes::system.run_if(resource_changed::<ChannelVolumes>));
+    }
+}
@@ -6,6 +6,7 @@ pub struct ShowcaseCamera {
     pub orbit: Vec3,
     pub distance: f32,
     pub mouse_sensitivity: f32,
+    pub invert_y: bool,
     pub zoom_scale: f32,
 }
 impl ShowcaseCamera {
@@ -22,7 +23,7 @@ impl ShowcaseCamera {
             // ROTATION 
             let (mut rx, mut ry, rz) = transform.rotation.to_euler(EulerRot::YXZ);
             rx += (-delta.x * camera.mouse_sensitivity).to_radians();
-            ry += (-delta.y * camera.mouse_sensitivity).to_radians();
+            ry += (-delta.y * camera.mouse_sensitivity * if camera.invert_y { -1.0 } else { 1.0 }).to_radians();
             ry = ry.clamp(-90_f32.to_radians(), 90_f32.to_radians());
             transform.rotation = Quat::from_euler(EulerRot::YXZ, rx, ry, rz);
 
@@ -56,6 +57,7 @@ impl Default for ShowcaseCamera {
             orbit: Vec3::ZERO,
             distance: 2.0,
             mouse_sensitivity: 0.1,
+            invert_y: false,
             zoom_scale: 25.0,
         }
     }
@@ -0,0 +1,213 @@
+use bevy::{gltf::GltfMaterialName, prelude::*, scene::SceneInstanceReady};
+use serde::{Deserialize, Serialize};
+
+
+/// Appearance of the player character chosen in the character creator.
+/// Each field is an index into the options of the matching [`AppearanceSlot`].
+#[derive(Resource, Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(default)]
+pub struct CharacterAppearance {
+    pub voice_tone: usize,
+    pub skin_tone: usize,
+    pub skin_type: usize,
+    pub hairstyle: usize,
+    pub hair_color: usize,
+    pub eyes: usize,
+}
+
+/// Customizable parts of the [`CharacterAppearance`]
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AppearanceSlot {
+    VoiceTone,
+    SkinTone,
+    SkinType,
+    Hairstyle,
+    HairColor,
+    Eyes,
+}
+impl AppearanceSlot {
+    /// All slots in the order they are displayed
+    pub const ALL: [AppearanceSlot; 6] = [
+        AppearanceSlot::VoiceTone,
+        AppearanceSlot::SkinTone,
+        AppearanceSlot::SkinType,
+        AppearanceSlot::Hairstyle,
+        AppearanceSlot::HairColor,
+        AppearanceSlot::Eyes,
+    ];
+    /// Display name of the slot
+    pub fn name(self) -> &'static str {
+        match self {
+            AppearanceSlot::VoiceTone => "Voice tone",
+            AppearanceSlot::SkinTone => "Skin tone",
+            AppearanceSlot::SkinType => "Skin type",
+            AppearanceSlot::Hairstyle => "Hairstyle",
+            AppearanceSlot::HairColor => "Hair color",
+            AppearanceSlot::Eyes => "Eyes",
+        }
+    }
+    /// Display names of the options for this slot
+    pub fn options(self) -> &'static [&'static str] {
+        match self {
+            AppearanceSlot::VoiceTone => &["Deep", "Low", "Neutral", "Bright", "High"],
+            AppearanceSlot::SkinTone => &["Porcelain", "Ivory", "Sand", "Honey", "Bronze", "Umber", "Espresso", "Ebony"],
+            AppearanceSlot::SkinType => &["Smooth", "Matte", "Weathered", "Synthetic"],
+            AppearanceSlot::Hairstyle => &["Default", "Shaved"],
+            AppearanceSlot::HairColor => &["Black", "Brown", "Blonde", "Red", "Platinum", "Neon pink", "Cyber blue", "Toxic green"],
+            AppearanceSlot::Eyes => &["Brown", "Hazel", "Green", "Blue", "Grey", "Amber", "Crimson", "Chrome"],
+        }
+    }
+    /// Get the selected option of this slot
+    pub fn get(self, appearance: &CharacterAppearance) -> usize {
+        match self {
+            AppearanceSlot::VoiceTone => appearance.voice_tone,
+            AppearanceSlot::SkinTone => appearance.skin_tone,
+            AppearanceSlot::SkinType => appearance.skin_type,
+            AppearanceSlot::Hairstyle => appearance.hairstyle,
+            AppearanceSlot::HairColor => appearance.hair_color,
+            AppearanceSlot::Eyes => appearance.eyes,
+        }
+    }
+    /// Set the selected option of this slot, clamped to the available options
+    pub fn set(self, appearance: &mut CharacterAppearance, index: usize) {
+        let index = index.min(self.options().len() - 1);
+        match self {
+            AppearanceSlot::VoiceTone => appearance.voice_tone = index,
+            AppearanceSlot::SkinTone => appearance.skin_tone = index,
+            AppearanceSlot::SkinType => appearance.skin_type = index,
+            AppearanceSlot::Hairstyle => appearance.hairstyle = index,
+            AppearanceSlot::HairColor => appearance.hair_color = index,
+            AppearanceSlot::Eyes => appearance.eyes = index,
+        }
+    }
+}
+
+/// Marks a spawned glTF scene whose materials follow the [`CharacterAppearance`].
+/// Parts are recognized by the material names used by Ready Player Me avatars.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct CharacterModel;
+impl CharacterModel {
+    /// Base color tints for [`AppearanceSlot::SkinTone`]
+    const SKIN_TONES: [Color; 8] = [
+        Color::srgb(1.00, 0.92, 0.88),
+        Color::srgb(0.96, 0.84, 0.74),
+        Color::srgb(0.90, 0.74, 0.60),
+        Color::srgb(0.82, 0.62, 0.44),
+        Color::srgb(0.68, 0.48, 0.32),
+        Color::srgb(0.52, 0.36, 0.24),
+        Color::srgb(0.38, 0.26, 0.18),
+        Color::srgb(0.26, 0.18, 0.13),
+    ];
+    /// Roughness and metallic values for [`AppearanceSlot::SkinType`]
+    const SKIN_TYPES: [(f32, f32); 4] = [(0.45, 0.0), (0.85, 0.0), (1.0, 0.0), (0.25, 0.65)];
+    /// Base color tints for [`AppearanceSlot::HairColor`]
+    const HAIR_COLORS: [Color; 8] = [
+        Color::srgb(0.08, 0.07, 0.07),
+        Color::srgb(0.36, 0.22, 0.12),
+        Color::srgb(0.93, 0.80, 0.52),
+        Color::srgb(0.70, 0.22, 0.10),
+        Color::srgb(0.92, 0.92, 0.90),
+        Color::srgb(1.00, 0.25, 0.65),
+        Color::srgb(0.15, 0.75, 1.00),
+        Color::srgb(0.45, 1.00, 0.20),
+    ];
+    /// Base color tints for [`AppearanceSlot::Eyes`]
+    const EYE_COLORS: [Color; 8] = [
+        Color::srgb(0.40, 0.25, 0.13),
+        Color::srgb(0.55, 0.45, 0.25),
+        Color::srgb(0.30, 0.55, 0.30),
+        Color::srgb(0.30, 0.50, 0.85),
+        Color::srgb(0.60, 0.64, 0.66),
+        Color::srgb(0.85, 0.60, 0.15),
+        Color::srgb(0.90, 0.10, 0.15),
+        Color::srgb(0.85, 0.88, 0.92),
+    ];
+    /// Observer for tagging the parts once the scene is spawned.
+    /// The materials are cloned, so modifying them does not affect other instances of the model.
+    fn ready(
+        trigger: Trigger<SceneInstanceReady>,
+        mut commands: Commands,
+        models: Query<(), With<CharacterModel>>,
+        children: Query<&Children>,
+        parts: Query<(&MeshMaterial3d<StandardMaterial>, &GltfMaterialName)>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        let entity = trigger.target();
+        if !models.contains(entity) { return; }
+
+        for descendant in children.iter_descendants(entity) {
+            let Ok((material, name)) = parts.get(descendant) else { continue };
+            let Some(part) = CharacterPart::from_material_name(&name.0) else { continue };
+            let Some(material) = materials.get(&material.0).cloned() else { continue };
+            commands.entity(descendant).insert((part, MeshMaterial3d(materials.add(material))));
+        }
+    }
+}
+
+/// Part of the [`CharacterModel`] affected by the [`CharacterAppearance`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterPart {
+    Skin,
+    Hair,
+    Eyes,
+}
+impl CharacterPart {
+    /// Recognize the part from the glTF material name
+    fn from_material_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        if name.contains("skin") || name.contains("body") { return Some(CharacterPart::Skin); }
+        if name.contains("hair") { return Some(CharacterPart::Hair); }
+        if name.contains("eye") { return Some(CharacterPart::Eyes); }
+        None
+    }
+    /// System for updating the materials and visibility of the parts
+    fn system(
+        appearance: Res<CharacterAppearance>,
+        mut query: Query<(Ref<CharacterPart>, &MeshMaterial3d<StandardMaterial>, &mut Visibility)>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        for (part, material, mut visibility) in &mut query {
+            // Only update newly tagged parts unless the appearance changed
+            if !appearance.is_changed() && !part.is_added() { continue; }
+            let Some(material) = materials.get_mut(&material.0) else { continue };
+            let pick = |colors: &[Color], index: usize| colors[index.min(colors.len() - 1)];
+            match *part {
+                CharacterPart::Skin => {
+                    let (roughness, metallic) = CharacterModel::SKIN_TYPES[appearance.skin_type.min(CharacterModel::SKIN_TYPES.len() - 1)];
+                    material.base_color = pick(&CharacterModel::SKIN_TONES, appearance.skin_tone);
+                    material.perceptual_roughness = roughness;
+                    material.metallic = metallic;
+                },
+                CharacterPart::Hair => {
+                    material.base_color = pick(&CharacterModel::HAIR_COLORS, appearance.hair_color);
+                    // The shaved hairstyle hides the hair mesh
+                    visibility.set_if_neq(if appearance.hairstyle == 1 { Visibility::Hidden } else { Visibility::Inherited });
+                },
+                CharacterPart::Eyes => {
+                    material.base_color = pick(&CharacterModel::EYE_COLORS, appearance.eyes);
+                },
+            }
+        }
+    }
+}
+
+/// Sent when the player changes a part of the [`CharacterAppearance`]
+#[derive(Event, Clone, Copy, Debug)]
+pub struct AppearanceChanged {
+    /// The changed slot
+    pub slot: AppearanceSlot,
+    /// The newly selected option
+    pub index: usize,
+}
+
+
+/// Plugin with the character appearance data
+pub struct CharacterPlugin;
+impl Plugin for CharacterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CharacterAppearance>();
+        app.add_event::<AppearanceChanged>();
+        app.add_observer(CharacterModel::ready);
+        app.add_systems(Update, CharacterPart::system);
+    }
+}
@@ -0,0 +1,146 @@
+use bevy::{asset::{io::Reader, AssetLoader, LoadContext, ReadAssetBytesError}, prelude::*};
+use serde::Deserialize;
+
+
+/// Credits roll loaded from a `.credits.ron` manifest.
+/// The license texts referenced by the manifest are read together with it.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct Credits {
+    /// Sections in the order they are rolled
+    pub sections: Vec<CreditsSection>,
+    /// License texts in the order they are rolled
+    pub licenses: Vec<CreditsLicense>,
+}
+
+/// Titled group of credited roles
+#[derive(Deserialize, Debug, Clone)]
+pub struct CreditsSection {
+    pub title: String,
+    #[serde(default)]
+    pub entries: Vec<CreditsEntry>,
+}
+
+/// Role with the names credited for it
+#[derive(Deserialize, Debug, Clone)]
+pub struct CreditsEntry {
+    pub role: String,
+    #[serde(default)]
+    pub names: Vec<String>,
+}
+
+/// Full text of a third-party license
+#[derive(Debug, Clone)]
+pub struct CreditsLicense {
+    pub title: String,
+    pub text: String,
+}
+
+/// Layout of the manifest file, licenses are referenced by their asset path
+#[derive(Deserialize)]
+struct CreditsManifest {
+    #[serde(default)]
+    sections: Vec<CreditsSection>,
+    #[serde(default)]
+    licenses: Vec<LicenseReference>,
+}
+
+/// License entry of the manifest file
+#[derive(Deserialize)]
+struct LicenseReference {
+    title: String,
+    path: String,
+}
+
+
+/// Errors that can happen while loading [`Credits`]
+#[derive(Debug)]
+pub enum CreditsLoaderError {
+    /// Reading the manifest failed
+    Io(std::io::Error),
+    /// The manifest is not valid
+    Parse(ron::error::SpannedError),
+    /// Reading a referenced license failed
+    License(ReadAssetBytesError),
+}
+impl std::fmt::Display for CreditsLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CreditsLoaderError::Io(error) => write!(f, "{error}"),
+            CreditsLoaderError::Parse(error) => write!(f, "{error}"),
+            CreditsLoaderError::License(error) => write!(f, "{error}"),
+        }
+    }
+}
+impl std::error::Error for CreditsLoaderError {}
+
+/// Loader for the [`Credits`] manifest
+#[derive(Default)]
+pub struct CreditsLoader;
+impl AssetLoader for CreditsLoader {
+    type Asset = Credits;
+    type Settings = ();
+    type Error = CreditsLoaderError;
+    async fn load(&self, reader: &mut dyn Reader, _settings: &(), load_context: &mut LoadContext<'_>) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.map_err(CreditsLoaderError::Io)?;
+        let manifest: CreditsManifest = ron::de::from_bytes(&bytes).map_err(CreditsLoaderError::Parse)?;
+
+        // Read the license texts, this also reloads the credits when a license changes
+        let mut licenses = Vec::new();
+        for license in manifest.licenses {
+            let bytes = load_context.read_asset_bytes(license.path).await.map_err(CreditsLoaderError::License)?;
+            licenses.push(CreditsLicense { title: license.title, text: String::from_utf8_lossy(&bytes).into_owned() });
+        }
+
+        Ok(Credits { sections: manifest.sections, licenses })
+    }
+    fn extensions(&self) -> &[&str] {
+        &["credits.ron"]
+    }
+}
+
+
+/// Plugin registering the [`Credits`] asset
+pub struct CreditsPlugin;
+impl Plugin for CreditsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Credits>();
+        app.init_asset_loader::<CreditsLoader>();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_manifest() {
+        let manifest: CreditsManifest = ron::from_str(r#"(
+            sections: [
+                (title: "Bevypunk", entries: [(role: "Created by", names: ["IDEDARY"])]),
+                (title: "Empty"),
+            ],
+            licenses: [(title: "Font", path: "fonts/LICENSE.txt")],
+        )"#).unwrap();
+
+        assert_eq!(manifest.sections.len(), 2);
+        assert_eq!(manifest.sections[0].entries[0].role, "Created by");
+        assert_eq!(manifest.sections[0].entries[0].names, ["IDEDARY"]);
+        assert!(manifest.sections[1].entries.is_empty());
+        assert_eq!(manifest.licenses[0].path, "fonts/LICENSE.txt");
+    }
+
+    #[test]
+    fn parses_empty_manifest() {
+        let manifest: CreditsManifest = ron::from_str("()").unwrap();
+        assert!(manifest.sections.is_empty());
+        assert!(manifest.licenses.is_empty());
+    }
+
+    #[test]
+    fn parses_bundled_credits() {
+        let manifest: CreditsManifest = ron::from_str(include_str!("../../../assets/bevypunk.credits.ron")).unwrap();
+        assert!(!manifest.sections.is_empty());
+    }
+}
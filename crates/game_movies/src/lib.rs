@@ -1,25 +1,89 @@
-use bevy::prelude::*;
+use std::{sync::Arc, time::Duration};
+
+use bevy::{asset::{io::Reader, AssetLoadError, AssetLoader, LoadContext, LoadState}, ecs::system::SystemParam, prelude::*};
 use bevy_kira_audio::prelude::*;
+use bevy_lunex::*;
+use game_audio::{decibels, DucksMusic, MusicChannel, SfxChannel, VoiceChannel};
 use vleue_kinetoscope::*;
 
-#[derive(Component)]
+/// Movie made of an animated image and any number of audio tracks.
+/// The movie keeps its own clock, the video frames are picked from it and the audio is seeked to it,
+/// so both stay in lock-step when the movie is paused, seeked or sped up.
+/// The clock runs on virtual time, so pausing it pauses the movie as well.
+/// Music is ducked while a movie with audio plays.
+#[derive(Component, Clone)]
+#[require(Sprite)]
 pub struct Movie {
     pub playback: MoviePlayback,
     pub video_src: Handle<AnimatedImage>,
-    pub audio_src: Handle<AudioSource>,
-    audio_ins: Option<Handle<AudioInstance>>,
+    /// Audio tracks played together with the video
+    pub tracks: Vec<MovieTrack>,
+    /// Language of the tracks to play, tracks without a language always play
+    pub language: Option<String>,
+    /// Subtitles shown by the [`SubtitleLayer`] of this movie
+    pub subtitles: Option<Handle<Subtitles>>,
+    /// Frames per second overriding the frame delays stored in the video
+    pub framerate: Option<f32>,
+    /// Which track is corrected when the audio and video drift apart
+    pub sync: MovieSync,
+    /// How far the audio and video can drift apart before being corrected
+    pub drift_tolerance: Duration,
+    /// Named moments that trigger [`MovieCue`] when the playback crosses them
+    pub cues: Vec<(MoviePosition, String)>,
+    /// Instances of the playing tracks, the first one leads the drift correction
+    audio_ins: Vec<Handle<AudioInstance>>,
     state: MovieState,
-
+    /// Playback position in seconds
+    time: f32,
+    /// Playback speed multiplier
+    speed: f32,
+    /// Start of each frame in seconds followed by the end of the last one, built when the video loads
+    timeline: Vec<f32>,
+    /// Index of the frame currently on screen
+    frame: Option<usize>,
+    /// Frame to jump to once the video loads and its timeline is known
+    pending_frame: Option<usize>,
+    /// If the virtual time is paused and the audio with it
+    suspended: bool,
+    /// If the audio instance needs to catch up with a change of state, position or speed
+    dirty: bool,
+    /// Timer for measuring the drift
+    drift_timer: Timer,
+    /// Position in seconds from which the cues were not triggered yet
+    cue_from: f32,
 }
 impl Movie {
-    /// Play new movie
+    /// How often the drift between the audio and video is measured
+    const DRIFT_INTERVAL: Duration = Duration::from_millis(250);
+    /// Delay of the frames that do not store one, the same browsers use
+    const DEFAULT_DELAY: f32 = 0.1;
+    /// Play new movie with a voice track
     pub fn play(video_src: Handle<AnimatedImage>, audio_src: Handle<AudioSource>) -> Self {
+        Movie::new(video_src).track(MovieTrack::new(audio_src))
+    }
+    /// Play new movie without audio
+    pub fn new(video_src: Handle<AnimatedImage>) -> Self {
         Self {
             playback: MoviePlayback::Despawn,
             video_src,
-            audio_src,
-            audio_ins: None,
-            state: MovieState::Playing,
+            tracks: Vec::new(),
+            language: None,
+            subtitles: None,
+            framerate: None,
+            sync: MovieSync::FollowAudio,
+            drift_tolerance: Duration::from_millis(80),
+            cues: Vec::new(),
+            audio_ins: Vec::new(),
+            state: MovieState::Loading,
+            time: 0.0,
+            speed: 1.0,
+            timeline: Vec::new(),
+            frame: None,
+            pending_frame: None,
+            suspended: false,
+            dirty: false,
+            drift_timer: Timer::new(Movie::DRIFT_INTERVAL, TimerMode::Repeating),
+            cue_from: 0.0,
         }
     }
     /// Set the movie playback
@@ -27,46 +91,427 @@ impl Movie {
         self.playback = playback;
         self
     }
+    /// Add an audio track
+    pub fn track(mut self, track: MovieTrack) -> Self {
+        self.tracks.push(track);
+        self
+    }
+    /// Set the language of the tracks to play
+    pub fn language(mut self, language: impl std::fmt::Display) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+    /// Attach subtitles to the movie
+    pub fn subtitles(mut self, subtitles: Handle<Subtitles>) -> Self {
+        self.subtitles = Some(subtitles);
+        self
+    }
+    /// Play the video at a fixed framerate instead of the frame delays stored in it.
+    pub fn framerate(mut self, framerate: f32) -> Self {
+        self.framerate = Some(framerate);
+        self
+    }
+    /// Set which track is corrected when the audio and video drift apart
+    pub fn sync(mut self, sync: MovieSync) -> Self {
+        self.sync = sync;
+        self
+    }
+    /// Replace the default drift tolerance of 80 ms with a new one.
+    pub fn drift_tolerance(mut self, tolerance: Duration) -> Self {
+        self.drift_tolerance = tolerance;
+        self
+    }
+    /// Add a named cue triggered as [`MovieCue`] when the playback reaches the position
+    pub fn cue(mut self, position: impl Into<MoviePosition>, name: impl std::fmt::Display) -> Self {
+        self.cues.push((position.into(), name.to_string()));
+        self
+    }
+    /// The current state of the movie
+    pub fn state(&self) -> MovieState {
+        self.state
+    }
+    /// The current playback position
+    pub fn time(&self) -> Duration {
+        Duration::from_secs_f32(self.time)
+    }
+    /// The current playback speed
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+    /// Pause the video and audio on the current frame
+    pub fn pause(&mut self) {
+        if self.state != MovieState::Playing { return; }
+        self.state = MovieState::Paused;
+        self.dirty = true;
+    }
+    /// Continue a paused movie from where it was paused
+    pub fn resume(&mut self) {
+        if self.state != MovieState::Paused { return; }
+        self.state = MovieState::Playing;
+        self.dirty = true;
+    }
+    /// Jump to the given position, a paused movie stays paused.
+    /// Cues between the old and new position are not triggered, cues from the new position on are.
+    pub fn seek(&mut self, time: Duration) {
+        if self.state.finished() { return; }
+        self.time = time.as_secs_f32();
+        self.cue_from = self.time;
+        self.pending_frame = None;
+        self.dirty = true;
+    }
+    /// Jump to the given frame, a paused movie stays paused
+    pub fn seek_frame(&mut self, frame: usize) {
+        if self.timeline.is_empty() {
+            if !self.state.finished() { self.pending_frame = Some(frame); }
+            return;
+        }
+        self.seek(Duration::from_secs_f32(self.seconds(MoviePosition::Frame(frame))));
+    }
+    /// Change the playback speed of both video and audio, `1.0` is the normal speed
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed.max(0.0);
+        self.dirty = true;
+    }
+    /// Position of the movie in seconds
+    fn seconds(&self, position: MoviePosition) -> f32 {
+        match position {
+            MoviePosition::Time(time) => time.as_secs_f32(),
+            MoviePosition::Frame(frame) => self.timeline.get(frame).or(self.timeline.last()).copied().unwrap_or(0.0),
+        }
+    }
+    /// Names of the cues between the last checked position and the clock in the order they are reached
+    fn crossed_cues(&self) -> Vec<String> {
+        let mut crossed: Vec<(f32, &String)> = self.cues.iter()
+            .map(|(position, name)| (self.seconds(*position), name))
+            .filter(|(time, _)| (self.cue_from..self.time).contains(time))
+            .collect();
+        crossed.sort_by(|a, b| a.0.total_cmp(&b.0));
+        crossed.into_iter().map(|(_, name)| name.clone()).collect()
+    }
+    /// Build the start of each frame from the frame delays or the fixed framerate
+    fn build_timeline(&mut self, video: &AnimatedImage) {
+        let mut start = 0.0;
+        self.timeline = std::iter::once(0.0).chain(video.frames.iter().map(|frame| {
+            start += match self.framerate {
+                Some(framerate) => 1.0 / framerate.max(f32::EPSILON),
+                None => match frame.delay {
+                    (0, _) | (_, 0) => Movie::DEFAULT_DELAY,
+                    (numer, denom) => numer as f32 / denom as f32 / 1000.0,
+                },
+            };
+            start
+        })).collect();
+    }
+    /// Index of the frame shown at the position in seconds
+    fn frame_at(&self, time: f32) -> usize {
+        self.timeline.partition_point(|start| *start <= time).saturating_sub(1).min(self.timeline.len().saturating_sub(2))
+    }
+    /// Stop the movie for good in the given state, the last frame stays on screen unless it despawns
+    fn finish(&mut self, commands: &mut Commands, entity: Entity, state: MovieState) {
+        match self.playback {
+            MoviePlayback::Despawn => {
+                commands.entity(entity).despawn();
+            }
+            _ => {
+                // Let the music come back
+                commands.entity(entity).remove::<DucksMusic>();
+                self.state = state
+            },
+        }
+    }
+    /// Tracks matching the language of the movie
+    fn active_tracks(&self) -> impl Iterator<Item = &MovieTrack> {
+        self.tracks.iter().filter(|track| track.language.is_none() || track.language == self.language)
+    }
+    /// Start new instances of the tracks from the current position
+    fn play_audio(&mut self, channels: &MovieChannels) {
+        self.audio_ins = self.active_tracks().map(|track| channels.play(track, self.time, self.speed)).collect();
+    }
     /// System for controlling the movie playback
-    fn system(mut commands: Commands, mut movies: Query<(Option<&mut AnimatedImageController>, &mut Movie, Entity)>, mut audio_assets: ResMut<Assets<AudioInstance>>, audio: Res<Audio>) {
-        for (controller_option, mut movie, entity) in &mut movies {
-
-            // Start playing if it is not already
-            if controller_option.is_none() && movie.audio_ins.is_none() {
-                commands.entity(entity).insert(AnimatedImageController::play(movie.video_src.clone()));
-                movie.audio_ins = Some(audio.play(movie.audio_src.clone()).handle());
-            
-            } else if let (Some(audio_instance), Some(mut controller)) = (audio_assets.get_mut(movie.audio_ins.as_ref().expect("Must be set")), controller_option) {
-
-                // Check if both are stopped
-                let video_stopped = controller.current_frame() == controller.frame_count() || controller.play_count() >= 1;
-                let audio_stopped = audio_instance.state() == PlaybackState::Stopped;
-
-                // Stop movie from looping if it ended
-                if video_stopped && !controller.paused() { controller.pause();}
-
-                // Movie ended
-                if video_stopped && audio_stopped && movie.state != MovieState::Ended {
-                    commands.trigger_targets(MovieEnded, entity);
-                    match movie.playback {
-                        MoviePlayback::Repeat => {
-                            controller.reset();
-                            movie.audio_ins = Some(audio.play(movie.audio_src.clone()).handle());
+    fn system(
+        mut commands: Commands,
+        mut movies: Query<(&mut Movie, &mut Sprite, Entity)>,
+        assets: MovieAssets,
+        mut audio_assets: ResMut<Assets<AudioInstance>>,
+        channels: MovieChannels,
+        time: Res<Time<Virtual>>,
+    ) {
+        for (mut movie, mut sprite, entity) in &mut movies {
+            if movie.state.finished() { continue; }
+
+            // Wait until both the video and audio are loaded
+            if movie.state == MovieState::Loading {
+                if let Some(error) = assets.failed(&movie) {
+                    warn!("Movie failed to load: {error}");
+                    commands.trigger_targets(MovieFailed { error: error.to_string() }, entity);
+                    commands.trigger_targets(MovieEnded { reason: MovieEndReason::Failed }, entity);
+                    movie.finish(&mut commands, entity, MovieState::Failed);
+                    continue;
+                }
+                if !assets.loaded(&movie) { continue; }
+
+                // Start playing
+                if let Some(video) = assets.videos.get(&movie.video_src) { movie.build_timeline(video); }
+                if let Some(frame) = movie.pending_frame.take() {
+                    movie.time = movie.seconds(MoviePosition::Frame(frame));
+                    movie.cue_from = movie.time;
+                }
+                if movie.active_tracks().next().is_some() { commands.entity(entity).insert(DucksMusic); }
+                movie.state = MovieState::Playing;
+                movie.play_audio(&channels);
+                movie.dirty = false;
+            }
+
+            let Some(video) = assets.videos.get(&movie.video_src) else { continue };
+
+            // Hold the audio while the virtual time is paused
+            if movie.suspended != time.is_paused() {
+                movie.suspended = time.is_paused();
+                movie.dirty = true;
+            }
+
+            // Bring the audio in line with the movie
+            if movie.dirty {
+                movie.dirty = false;
+                // The audio reports the old position until the changes are applied
+                movie.drift_timer.reset();
+                let stopped = movie.audio_ins.iter().any(|handle| audio_assets.get(handle).is_none_or(|audio_instance| audio_instance.state() == PlaybackState::Stopped));
+                let halted = movie.state == MovieState::Paused || movie.suspended;
+                match movie.state {
+                    MovieState::Paused | MovieState::Playing if halted => for handle in &movie.audio_ins {
+                        let Some(audio_instance) = audio_assets.get_mut(handle) else { continue };
+                        let _ = audio_instance.pause(AudioTween::default());
+                        let _ = audio_instance.seek_to(movie.time as f64);
+                    },
+                    // Some audio already ended, so all tracks are played again to keep them together
+                    MovieState::Playing if stopped => {
+                        for handle in &movie.audio_ins {
+                            if let Some(audio_instance) = audio_assets.get_mut(handle) { let _ = audio_instance.stop(AudioTween::default()); }
                         }
-                        MoviePlayback::Despawn => {
-                            commands.entity(entity).despawn();
+                        movie.play_audio(&channels);
+                    },
+                    MovieState::Playing => for handle in &movie.audio_ins {
+                        let Some(audio_instance) = audio_assets.get_mut(handle) else { continue };
+                        let _ = audio_instance.seek_to(movie.time as f64);
+                        let _ = audio_instance.set_playback_rate(movie.speed as f64, AudioTween::default());
+                        let _ = audio_instance.resume(AudioTween::default());
+                    },
+                    _ => {},
+                }
+            }
+
+            // Advance the clock
+            if movie.state == MovieState::Playing { movie.time += time.delta_secs() * movie.speed; }
+
+            // Measure the drift between the lead track and the clock once in a while
+            if movie.state == MovieState::Playing && movie.drift_timer.tick(time.delta()).just_finished() {
+                let lead = movie.audio_ins.first().and_then(|handle| audio_assets.get(handle)).map(|audio_instance| audio_instance.state());
+                if let Some(PlaybackState::Playing { position }) = lead {
+                    let drift = movie.time - position as f32;
+                    if drift.abs() > movie.drift_tolerance.as_secs_f32() {
+                        debug!("Correcting movie drift of {drift:.3}s");
+                        match movie.sync {
+                            // Skips or holds the frames until the video catches up
+                            MovieSync::FollowAudio => movie.time = position as f32,
+                            MovieSync::FollowVideo => for handle in &movie.audio_ins {
+                                if let Some(audio_instance) = audio_assets.get_mut(handle) { let _ = audio_instance.seek_to(movie.time as f64); }
+                            },
                         }
-                        _ => {
-                            movie.state = MovieState::Ended
-                        },
                     }
                 }
             }
+
+            // Show the frame matching the clock
+            let duration = movie.timeline.last().copied().unwrap_or(0.0);
+            let frame = movie.frame_at(movie.time);
+            if movie.frame != Some(frame) {
+                if let Some(image) = video.frames.get(frame) { sprite.image = image.image.clone(); }
+                movie.frame = Some(frame);
+            }
+
+            // Trigger the cues the playback crossed since the last frame
+            if movie.time > movie.cue_from {
+                for name in movie.crossed_cues() {
+                    commands.trigger_targets(MovieCue { name }, entity);
+                }
+                // Holding the frames after a drift correction does not trigger the cues again
+                movie.cue_from = movie.time;
+            }
+
+            // Check if the video and all tracks are stopped
+            let video_stopped = movie.time >= duration;
+            let audio_stopped = movie.audio_ins.iter().all(|handle| audio_assets.get(handle).is_none_or(|audio_instance| audio_instance.state() == PlaybackState::Stopped));
+
+            // Movie ended
+            if video_stopped && audio_stopped && movie.state == MovieState::Playing {
+                commands.trigger_targets(MovieEnded { reason: MovieEndReason::Completed }, entity);
+                if let MoviePlayback::Repeat = movie.playback {
+                    movie.time = 0.0;
+                    movie.cue_from = 0.0;
+                    movie.play_audio(&channels);
+                } else {
+                    movie.finish(&mut commands, entity, MovieState::Ended);
+                }
+            }
         }
     }
+    /// Observer ending the movie early, the audio is faded out and the current frame stays on screen
+    fn skip(
+        trigger: Trigger<SkipMovie>,
+        mut commands: Commands,
+        mut movies: Query<&mut Movie>,
+        mut audio_assets: ResMut<Assets<AudioInstance>>,
+    ) {
+        let entity = trigger.target();
+        let Ok(mut movie) = movies.get_mut(entity) else { return };
+        if movie.state.finished() { return; }
+
+        for handle in &movie.audio_ins {
+            if let Some(audio_instance) = audio_assets.get_mut(handle) { let _ = audio_instance.stop(AudioTween::linear(Duration::from_millis(250))); }
+        }
+
+        // A skipped movie does not repeat
+        commands.trigger_targets(MovieEnded { reason: MovieEndReason::Skipped }, entity);
+        movie.finish(&mut commands, entity, MovieState::Ended);
+    }
+}
+
+/// Audio track of a [`Movie`]
+#[derive(Debug, Clone)]
+pub struct MovieTrack {
+    pub source: Handle<AudioSource>,
+    /// Channel the track plays on, which decides the volume preference it follows
+    pub channel: MovieChannel,
+    /// Language of the track, tracks with a language only play when it matches [`Movie::language`]
+    pub language: Option<String>,
+    /// Volume of the track in range `0.0..=1.0`
+    pub volume: f32,
+}
+impl MovieTrack {
+    /// Creates new instance playing on the voice channel
+    pub fn new(source: Handle<AudioSource>) -> Self {
+        Self {
+            source,
+            channel: MovieChannel::Voice,
+            language: None,
+            volume: 1.0,
+        }
+    }
+    /// Set the channel the track plays on
+    pub fn channel(mut self, channel: MovieChannel) -> Self {
+        self.channel = channel;
+        self
+    }
+    /// Set the language of the track
+    pub fn language(mut self, language: impl std::fmt::Display) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+    /// Set the volume of the track
+    pub fn volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+}
+
+/// Audio channel a [`MovieTrack`] plays on
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MovieChannel {
+    Music,
+    Sfx,
+    Voice,
+}
+
+/// Video and audio assets of the [`Movie`]s
+#[derive(SystemParam)]
+struct MovieAssets<'w> {
+    server: Res<'w, AssetServer>,
+    videos: Res<'w, Assets<AnimatedImage>>,
+    audio_sources: Res<'w, Assets<AudioSource>>,
+}
+impl MovieAssets<'_> {
+    /// Error of the first video or active track that failed to load
+    fn failed(&self, movie: &Movie) -> Option<Arc<AssetLoadError>> {
+        std::iter::once(self.server.load_state(&movie.video_src))
+            .chain(movie.active_tracks().map(|track| self.server.load_state(&track.source)))
+            .find_map(|state| if let LoadState::Failed(error) = state { Some(error) } else { None })
+    }
+    /// If the video and all active tracks are ready.
+    /// Priority assets are inserted directly, so the assets are checked instead of the load state.
+    fn loaded(&self, movie: &Movie) -> bool {
+        self.videos.contains(&movie.video_src) && movie.active_tracks().all(|track| self.audio_sources.contains(&track.source))
+    }
+}
+
+/// Audio channels the [`MovieTrack`]s can play on
+#[derive(SystemParam)]
+struct MovieChannels<'w> {
+    music: Res<'w, AudioChannel<MusicChannel>>,
+    sfx: Res<'w, AudioChannel<SfxChannel>>,
+    voice: Res<'w, AudioChannel<VoiceChannel>>,
+}
+impl MovieChannels<'_> {
+    /// Play the track from the given position in seconds
+    fn play(&self, track: &MovieTrack, time: f32, speed: f32) -> Handle<AudioInstance> {
+        match track.channel {
+            MovieChannel::Music => MovieChannels::play_on(&self.music, track, time, speed),
+            MovieChannel::Sfx => MovieChannels::play_on(&self.sfx, track, time, speed),
+            MovieChannel::Voice => MovieChannels::play_on(&self.voice, track, time, speed),
+        }
+    }
+    /// Play the track on the channel
+    fn play_on<T: Resource>(channel: &AudioChannel<T>, track: &MovieTrack, time: f32, speed: f32) -> Handle<AudioInstance> {
+        channel.play(track.source.clone()).start_from(time as f64).with_playback_rate(speed as f64).with_volume(decibels(track.volume)).handle()
+    }
+}
+
+/// Lets the player skip the [`Movie`] by holding Space, Enter, the left mouse button or South/Start on a gamepad.
+/// The progress drains when the button is released, so a stray press does not skip.
+#[derive(Component, Debug, Clone)]
+pub struct HoldToSkip {
+    /// How long the button has to be held
+    pub duration: Duration,
+    /// Progress of the hold from 0 to 1, useful for drawing the prompt
+    pub progress: f32,
+}
+impl HoldToSkip {
+    /// Creates new instance
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            progress: 0.0,
+        }
+    }
+    /// System for filling the progress and skipping the movie once it is full
+    fn system(
+        mut commands: Commands,
+        keys: Res<ButtonInput<KeyCode>>,
+        mouse: Res<ButtonInput<MouseButton>>,
+        gamepads: Query<&Gamepad>,
+        time: Res<Time<Virtual>>,
+        mut query: Query<(Entity, &mut HoldToSkip, &Movie)>,
+    ) {
+        let keyboard = keys.any_pressed([KeyCode::Space, KeyCode::Enter]);
+        let gamepad = gamepads.iter().any(|gamepad| gamepad.any_pressed([GamepadButton::South, GamepadButton::Start]));
+        let held = keyboard || gamepad || mouse.pressed(MouseButton::Left);
+
+        for (entity, mut skip, movie) in &mut query {
+            if movie.state.finished() { continue; }
+            let step = time.delta_secs() / skip.duration.as_secs_f32().max(f32::EPSILON);
+            let progress = if held { skip.progress + step } else { skip.progress - step * 2.0 }.clamp(0.0, 1.0);
+            if progress != skip.progress { skip.progress = progress; }
+            if skip.progress >= 1.0 { commands.trigger_targets(SkipMovie, entity); }
+        }
+    }
+}
+impl Default for HoldToSkip {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(1200))
+    }
 }
 
 /// What should the move do after it ends
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum MoviePlayback {
     /// Do nothing
     Stop,
@@ -76,21 +521,575 @@ pub enum MoviePlayback {
     Despawn,
 }
 
+/// Position in a [`Movie`], either as time or as a frame index
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MoviePosition {
+    Time(Duration),
+    Frame(usize),
+}
+impl From<Duration> for MoviePosition {
+    fn from(time: Duration) -> Self {
+        MoviePosition::Time(time)
+    }
+}
+impl From<usize> for MoviePosition {
+    fn from(frame: usize) -> Self {
+        MoviePosition::Frame(frame)
+    }
+}
+
+/// Which track follows the other when the audio and video of a [`Movie`] drift apart
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MovieSync {
+    /// Skip or hold video frames to match the audio
+    FollowAudio,
+    /// Seek the audio to match the video
+    FollowVideo,
+}
+
 /// The current state of the movie
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum MovieState {
+    /// Waiting for the video and audio to load
+    Loading,
     Playing,
+    Paused,
     Ended,
+    /// The video or audio failed to load
+    Failed,
+}
+impl MovieState {
+    /// If the movie will not play anymore
+    pub fn finished(self) -> bool {
+        matches!(self, MovieState::Ended | MovieState::Failed)
+    }
+}
+
+/// Triggered on a [`Movie`] when it ends
+#[derive(Event, Clone, Copy, Debug)]
+pub struct MovieEnded {
+    /// Why the movie ended
+    pub reason: MovieEndReason,
+}
+
+/// Why the [`Movie`] ended
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MovieEndReason {
+    /// Played to the end
+    Completed,
+    /// Ended early with [`SkipMovie`]
+    Skipped,
+    /// The video or audio failed to load, see [`MovieFailed`]
+    Failed,
+}
+
+/// Triggered on a [`Movie`] when its video or audio fails to load, followed by [`MovieEnded`]
+#[derive(Event, Clone, Debug)]
+pub struct MovieFailed {
+    /// Description of the load error
+    pub error: String,
+}
+
+/// Triggered on a [`Movie`] when the playback crosses one of its cues.
+/// Cues are triggered again when the movie repeats or is seeked back, cues after the point of a skip are not triggered.
+#[derive(Event, Clone, Debug)]
+pub struct MovieCue {
+    /// Name the cue was added with
+    pub name: String,
+}
+
+/// Trigger on a [`Movie`] to end it early, it is ended only once
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SkipMovie;
+
+
+
+/// Plays [`Movie`] entries back to back as children of this entity.
+/// Each entry is played with [`MoviePlayback::Stop`], the playback of the sequence decides what happens after the last one.
+#[derive(Component, Clone)]
+pub struct MovieSequence {
+    pub entries: Vec<SequenceEntry>,
+    pub playback: MoviePlayback,
+    /// Added to the entries that can be skipped
+    pub hold_to_skip: Option<HoldToSkip>,
+    /// The movie entity of the playing entry
+    current: Option<Entity>,
+    /// If the whole sequence is being skipped
+    skipping: bool,
+}
+impl MovieSequence {
+    /// Creates new instance
+    pub fn new(entries: impl IntoIterator<Item = SequenceEntry>) -> Self {
+        Self {
+            entries: entries.into_iter().collect(),
+            playback: MoviePlayback::Despawn,
+            hold_to_skip: None,
+            current: None,
+            skipping: false,
+        }
+    }
+    /// Set the sequence playback
+    pub fn playback(mut self, playback: MoviePlayback) -> Self {
+        self.playback = playback;
+        self
+    }
+    /// Let the player skip the entries by holding a button
+    pub fn hold_to_skip(mut self, hold_to_skip: HoldToSkip) -> Self {
+        self.hold_to_skip = Some(hold_to_skip);
+        self
+    }
+    /// Spawn the movie of the entry, the previous one is removed by its [`SequenceTransition`]
+    fn spawn_entry(&mut self, commands: &mut Commands, sequence: Entity, index: usize) {
+        let Some(entry) = self.entries.get(index) else { return };
+        let previous = self.current;
+
+        // Keep the new movie above the previous one while it fades in
+        if let Some(previous) = previous { commands.entity(previous).insert(UiDepth::Add(1.0)); }
+        let mut movie = commands.spawn((
+            UiLayout::window().full().pack(),
+            UiDepth::Add(1.1),
+            entry.movie.clone().playback(MoviePlayback::Stop),
+            Sprite { color: Color::WHITE.with_alpha(0.0), ..default() },
+            SequenceMovie { sequence, index },
+            SequenceTransition { timer: Timer::new(entry.transition.duration(), TimerMode::Once), previous },
+            ChildOf(sequence),
+        ));
+        if let (Some(hold_to_skip), true) = (&self.hold_to_skip, entry.skip != SkipRule::Never) {
+            movie.insert(HoldToSkip::new(hold_to_skip.duration));
+        }
+        self.current = Some(movie.id());
+    }
+    /// End the whole sequence, the last frame stays on screen unless it despawns
+    fn finish(&mut self, commands: &mut Commands, entity: Entity, reason: MovieEndReason) {
+        commands.trigger_targets(SequenceEnded { reason }, entity);
+        match self.playback {
+            MoviePlayback::Despawn => {
+                commands.entity(entity).despawn();
+            }
+            _ => {
+                self.current = None;
+            },
+        }
+    }
+    /// System for starting the newly added sequences
+    fn start(mut commands: Commands, mut query: Query<(Entity, &mut MovieSequence), Added<MovieSequence>>) {
+        for (entity, mut sequence) in &mut query {
+            if sequence.entries.is_empty() {
+                sequence.finish(&mut commands, entity, MovieEndReason::Completed);
+                continue;
+            }
+            sequence.spawn_entry(&mut commands, entity, 0);
+        }
+    }
+    /// Observer moving to the next entry when the playing one ends
+    fn entry_ended(trigger: Trigger<MovieEnded>, mut commands: Commands, movies: Query<&SequenceMovie>, mut sequences: Query<&mut MovieSequence>) {
+        let Ok(entry) = movies.get(trigger.target()) else { return };
+        let Ok(mut sequence) = sequences.get_mut(entry.sequence) else { return };
+        if sequence.current != Some(trigger.target()) { return; }
+
+        let reason = trigger.reason;
+        commands.trigger_targets(SequenceEntryEnded { index: entry.index, reason }, entry.sequence);
+
+        // Failed entries are passed over, the sequence carries on with the next one
+        let skipped = sequence.skipping || (reason == MovieEndReason::Skipped && sequence.entries[entry.index].skip == SkipRule::Sequence);
+        if !skipped && entry.index + 1 < sequence.entries.len() {
+            sequence.spawn_entry(&mut commands, entry.sequence, entry.index + 1);
+            return;
+        }
+        if !skipped && sequence.playback == MoviePlayback::Repeat {
+            sequence.spawn_entry(&mut commands, entry.sequence, 0);
+            return;
+        }
+
+        // The whole sequence ended
+        sequence.finish(&mut commands, entry.sequence, if skipped { MovieEndReason::Skipped } else { MovieEndReason::Completed });
+    }
+    /// Observer skipping the whole sequence when [`SkipMovie`] is triggered on it
+    fn skip(trigger: Trigger<SkipMovie>, mut commands: Commands, mut sequences: Query<&mut MovieSequence>) {
+        let Ok(mut sequence) = sequences.get_mut(trigger.target()) else { return };
+        let Some(current) = sequence.current else { return };
+        sequence.skipping = true;
+        commands.trigger_targets(SkipMovie, current);
+    }
+}
+
+/// Entry of a [`MovieSequence`]
+#[derive(Clone)]
+pub struct SequenceEntry {
+    pub movie: Movie,
+    /// How the entry replaces the previous one
+    pub transition: MovieTransition,
+    /// What skipping this entry does
+    pub skip: SkipRule,
+}
+impl SequenceEntry {
+    /// Creates new instance that cuts in and can be skipped on its own
+    pub fn new(movie: Movie) -> Self {
+        Self {
+            movie,
+            transition: MovieTransition::Cut,
+            skip: SkipRule::Entry,
+        }
+    }
+    /// Set how the entry replaces the previous one
+    pub fn transition(mut self, transition: MovieTransition) -> Self {
+        self.transition = transition;
+        self
+    }
+    /// Set what skipping this entry does
+    pub fn skip(mut self, skip: SkipRule) -> Self {
+        self.skip = skip;
+        self
+    }
+}
+
+/// How a [`SequenceEntry`] replaces the previous one
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MovieTransition {
+    /// Replace the last frame of the previous entry at once
+    Cut,
+    /// Fade in over the last frame of the previous entry
+    Crossfade(Duration),
+}
+impl MovieTransition {
+    /// How long the transition takes
+    fn duration(self) -> Duration {
+        match self {
+            MovieTransition::Cut => Duration::ZERO,
+            MovieTransition::Crossfade(duration) => duration,
+        }
+    }
+}
+
+/// What skipping a [`SequenceEntry`] does
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SkipRule {
+    /// Skip to the next entry
+    Entry,
+    /// Skip the rest of the sequence
+    Sequence,
+    /// The entry can not be skipped by holding a button
+    Never,
+}
+
+/// Marks the movie entity of a [`SequenceEntry`]
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SequenceMovie {
+    /// The [`MovieSequence`] entity
+    pub sequence: Entity,
+    /// Index of the entry
+    pub index: usize,
+}
+
+/// Fades the movie of a [`SequenceEntry`] in once it starts playing and removes the previous one
+#[derive(Component, Debug, Clone)]
+struct SequenceTransition {
+    timer: Timer,
+    previous: Option<Entity>,
+}
+impl SequenceTransition {
+    /// System for fading in the movies
+    fn system(mut commands: Commands, time: Res<Time<Virtual>>, mut query: Query<(Entity, &mut SequenceTransition, &Movie, &mut Sprite)>) {
+        for (entity, mut transition, movie, mut sprite) in &mut query {
+            // The previous entry stays on screen until the first frame is ready
+            if movie.state() == MovieState::Loading { continue; }
+
+            transition.timer.tick(time.delta());
+            sprite.color.set_alpha(if transition.timer.duration().is_zero() { 1.0 } else { transition.timer.fraction() });
+            if transition.timer.finished() {
+                if let Some(previous) = transition.previous { commands.entity(previous).despawn(); }
+                commands.entity(entity).remove::<SequenceTransition>();
+            }
+        }
+    }
+}
+
+/// Triggered on a [`MovieSequence`] when one of its entries ends
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SequenceEntryEnded {
+    /// Index of the entry
+    pub index: usize,
+    /// Why the entry ended
+    pub reason: MovieEndReason,
+}
+
+/// Triggered on a [`MovieSequence`] when all of its entries ended or it was skipped
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SequenceEnded {
+    /// [`MovieEndReason::Skipped`] if the rest of the sequence was skipped, otherwise [`MovieEndReason::Completed`]
+    pub reason: MovieEndReason,
+}
+
+
+/// Timed text track of a [`Movie`] loaded from a SubRip `.srt` or WebVTT `.vtt` file
+#[derive(Asset, TypePath, Debug, Clone, Default)]
+pub struct Subtitles {
+    /// Cues sorted by their start
+    pub cues: Vec<SubtitleCue>,
+}
+impl Subtitles {
+    /// Parse the SubRip or WebVTT text, both formats share the cue layout.
+    /// Formatting tags are stripped, the WebVTT notes, styles and regions and other blocks without a timing line are ignored.
+    pub fn parse(text: &str) -> Result<Self, SubtitlesLoaderError> {
+        let mut cues = Vec::new();
+        let mut lines = text.trim_start_matches('\u{feff}').lines().enumerate().peekable();
+        let mut block_start = true;
+        while let Some((index, line)) = lines.next() {
+            let first = std::mem::replace(&mut block_start, line.trim().is_empty());
+
+            // These blocks can contain anything, even an arrow, so they are skipped as a whole
+            if first && line.split_whitespace().next().is_some_and(|word| ["NOTE", "STYLE", "REGION"].contains(&word)) {
+                while lines.next_if(|(_, line)| !line.trim().is_empty()).is_some() {}
+                continue;
+            }
+            let Some((start, rest)) = line.split_once("-->") else { continue };
+
+            // WebVTT can have cue settings after the end time
+            let end = rest.split_whitespace().next().unwrap_or_default();
+            let (Some(start), Some(end)) = (parse_timestamp(start.trim()), parse_timestamp(end)) else { return Err(SubtitlesLoaderError::Timing(index + 1)) };
+
+            // The text continues until the next empty line
+            let mut text = Vec::new();
+            while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+                text.push(strip_tags(line.trim()));
+            }
+            cues.push(SubtitleCue { start, end, text: text.join("\n") });
+        }
+        cues.sort_by_key(|cue| cue.start);
+        Ok(Self { cues })
+    }
+    /// Text of the cue showing at the given time
+    pub fn text_at(&self, time: Duration) -> Option<&str> {
+        self.cues.iter().find(|cue| cue.start <= time && time < cue.end).map(|cue| cue.text.as_str())
+    }
+}
+
+/// Text shown between two points of the [`Movie`] playback
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleCue {
+    pub start: Duration,
+    pub end: Duration,
+    pub text: String,
+}
+
+/// Parse `HH:MM:SS,mmm` used by SubRip or `HH:MM:SS.mmm` and `MM:SS.mmm` used by WebVTT
+fn parse_timestamp(string: &str) -> Option<Duration> {
+    let (clock, millis) = string.split_once([',', '.'])?;
+    let parts: Vec<&str> = clock.split(':').collect();
+    if !(2..=3).contains(&parts.len()) { return None; }
+    let mut seconds = 0;
+    for part in parts { seconds = seconds * 60 + part.parse::<u64>().ok()?; }
+    Some(Duration::from_secs(seconds) + Duration::from_millis(millis.parse().ok()?))
+}
+
+/// Remove the `<b>`, `<i>`, `<v Speaker>` and similar tags and decode the basic entities
+fn strip_tags(line: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in line.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {},
+        }
+    }
+    text.replace("&lt;", "<").replace("&gt;", ">").replace("&nbsp;", " ").replace("&amp;", "&")
+}
+
+/// Errors that can happen while loading [`Subtitles`]
+#[derive(Debug)]
+pub enum SubtitlesLoaderError {
+    /// Reading the file failed
+    Io(std::io::Error),
+    /// The file is not valid UTF-8
+    Utf8(std::string::FromUtf8Error),
+    /// The timing line of a cue is not valid, holds the line number
+    Timing(usize),
+}
+impl std::fmt::Display for SubtitlesLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubtitlesLoaderError::Io(error) => write!(f, "{error}"),
+            SubtitlesLoaderError::Utf8(error) => write!(f, "{error}"),
+            SubtitlesLoaderError::Timing(line) => write!(f, "invalid cue timing on line {line}"),
+        }
+    }
+}
+impl std::error::Error for SubtitlesLoaderError {}
+
+/// Loader for the [`Subtitles`] files
+#[derive(Default)]
+pub struct SubtitlesLoader;
+impl AssetLoader for SubtitlesLoader {
+    type Asset = Subtitles;
+    type Settings = ();
+    type Error = SubtitlesLoaderError;
+    async fn load(&self, reader: &mut dyn Reader, _settings: &(), _load_context: &mut LoadContext<'_>) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.map_err(SubtitlesLoaderError::Io)?;
+        let text = String::from_utf8(bytes).map_err(SubtitlesLoaderError::Utf8)?;
+        Subtitles::parse(&text)
+    }
+    fn extensions(&self) -> &[&str] {
+        &["srt", "vtt"]
+    }
+}
+
+/// How the subtitles are displayed, shared by all [`SubtitleLayer`]s
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SubtitleSettings {
+    /// If the subtitles are shown
+    pub enabled: bool,
+    /// Height of the text in percent of the layer height
+    pub size: f32,
+}
+impl Default for SubtitleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            size: 4.0,
+        }
+    }
+}
+
+/// Lunex text showing the current subtitle of a [`Movie`], style it like any other text.
+/// The text size is taken from the [`SubtitleSettings`].
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Text2d)]
+pub struct SubtitleLayer {
+    /// The movie entity to follow
+    pub movie: Entity,
+}
+impl SubtitleLayer {
+    /// Creates new instance
+    pub fn new(movie: Entity) -> Self {
+        Self { movie }
+    }
+    /// System for syncing the text with the movie playback
+    fn system(
+        mut commands: Commands,
+        settings: Res<SubtitleSettings>,
+        movies: Query<&Movie>,
+        subtitles: Res<Assets<Subtitles>>,
+        mut query: Query<(Entity, Ref<SubtitleLayer>, &mut Text2d)>,
+    ) {
+        for (entity, layer, mut text) in &mut query {
+            if settings.is_changed() || layer.is_added() {
+                commands.entity(entity).insert(UiTextSize::from(Rh(settings.size)));
+            }
+
+            // Ended or despawned movies have no text
+            let movie = movies.get(layer.movie).ok().filter(|movie| !movie.state.finished());
+            let cue = movie.and_then(|movie| Some(subtitles.get(movie.subtitles.as_ref()?)?.text_at(movie.time())?.to_string()));
+            let cue = if settings.enabled { cue.unwrap_or_default() } else { String::new() };
+            if text.0 != cue { text.0 = cue; }
+        }
+    }
 }
 
-#[derive(Event)]
-pub struct MovieEnded;
 
 /// Plugin with VFX systems for our menu
 pub struct MoviePlugin;
 impl Plugin for MoviePlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<MovieEnded>();
-        app.add_systems(Update, Movie::system);
+        app.add_event::<MovieFailed>();
+        app.init_asset::<Subtitles>();
+        app.init_asset_loader::<SubtitlesLoader>();
+        app.init_resource::<SubtitleSettings>();
+        app.add_observer(Movie::skip);
+        app.add_observer(MovieSequence::entry_ended);
+        app.add_observer(MovieSequence::skip);
+        app.add_systems(Update, (MovieSequence::start, HoldToSkip::system, Movie::system, SequenceTransition::system, SubtitleLayer::system).chain());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_srt() {
+        let subtitles = Subtitles::parse("1\n00:00:01,500 --> 00:00:03,000\n<i>Hello</i> &amp; welcome\nto <b>Bevypunk</b>\n\n2\n00:01:00,000 --> 01:00:00,250\nBye\n").unwrap();
+
+        assert_eq!(subtitles.cues, [
+            SubtitleCue { start: Duration::from_millis(1500), end: Duration::from_millis(3000), text: "Hello & welcome\nto Bevypunk".into() },
+            SubtitleCue { start: Duration::from_secs(60), end: Duration::from_millis(3_600_250), text: "Bye".into() },
+        ]);
+    }
+
+    #[test]
+    fn parses_vtt() {
+        let subtitles = Subtitles::parse("\u{feff}WEBVTT\n\nNOTE This is a comment\n\nNOTE\nCues look like 00:01.000 --> 00:02.000\n\nSTYLE\n::cue { color: red } /* --> */\n\nintro\n00:02.000 --> 00:04.000 align:center line:90%\n<v Narrator>First</v>\n\n00:00.000 --> 00:01.000\nZero\n").unwrap();
+
+        assert_eq!(subtitles.cues.len(), 2);
+        assert_eq!(subtitles.cues[0].text, "Zero");
+        assert_eq!(subtitles.cues[1].start, Duration::from_secs(2));
+        assert_eq!(subtitles.cues[1].end, Duration::from_secs(4));
+        assert_eq!(subtitles.cues[1].text, "First");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn rejects_invalid_timing() {
+        let error = Subtitles::parse("WEBVTT\n\n00:01.000 --> soon\nText\n").unwrap_err();
+        assert!(matches!(error, SubtitlesLoaderError::Timing(3)));
+    }
+
+    #[test]
+    fn finds_text_at_time() {
+        let subtitles = Subtitles::parse("00:01.000 --> 00:02.000\nOne\n\n00:02.000 --> 00:03.000\nTwo\n").unwrap();
+
+        assert_eq!(subtitles.text_at(Duration::ZERO), None);
+        assert_eq!(subtitles.text_at(Duration::from_millis(1000)), Some("One"));
+        assert_eq!(subtitles.text_at(Duration::from_millis(2000)), Some("Two"));
+        assert_eq!(subtitles.text_at(Duration::from_millis(3000)), None);
+    }
+
+    /// Seek the movie to `from`, advance its clock to `to` and collect the crossed cues
+    fn crossing(movie: &mut Movie, from: f32, to: f32) -> Vec<String> {
+        movie.seek(Duration::from_secs_f32(from));
+        movie.time = to;
+        let crossed = movie.crossed_cues();
+        movie.cue_from = movie.time;
+        crossed
+    }
+
+    #[test]
+    fn crosses_cues_in_order() {
+        let mut movie = Movie::new(Handle::default()).cue(Duration::from_secs(2), "two").cue(Duration::from_secs(1), "one").cue(Duration::ZERO, "zero");
+
+        assert_eq!(crossing(&mut movie, 0.0, 2.5), ["zero", "one", "two"]);
+        // The end of the range is crossed by the next frame
+        assert_eq!(crossing(&mut movie, 0.5, 1.0), Vec::<String>::new());
+        assert_eq!(crossing(&mut movie, 1.0, 1.5), ["one"]);
+    }
+
+    #[test]
+    fn seeking_skips_cues() {
+        let mut movie = Movie::new(Handle::default()).cue(Duration::from_secs(1), "one").cue(Duration::from_secs(3), "three");
+
+        // Cues between the old and new position are not triggered
+        assert_eq!(crossing(&mut movie, 2.0, 2.5), Vec::<String>::new());
+        assert_eq!(crossing(&mut movie, 2.5, 3.5), ["three"]);
+        // Seeking back or repeating triggers them again
+        assert_eq!(crossing(&mut movie, 0.0, 3.5), ["one", "three"]);
+    }
+
+    #[test]
+    fn crosses_frame_cues() {
+        let mut movie = Movie::new(Handle::default()).cue(2usize, "third frame");
+        movie.timeline = vec![0.0, 0.1, 0.3, 0.4];
+
+        assert_eq!(crossing(&mut movie, 0.0, 0.25), Vec::<String>::new());
+        assert_eq!(crossing(&mut movie, 0.25, 0.35), ["third frame"]);
+        assert_eq!(movie.frame_at(0.35), 2);
+        assert_eq!(movie.frame_at(10.0), 2);
+    }
+
+    #[test]
+    fn parses_bundled_subtitles() {
+        let subtitles = Subtitles::parse(include_str!("../../../assets/movies/intro.vtt")).unwrap();
+        assert!(!subtitles.cues.is_empty());
+    }
+}
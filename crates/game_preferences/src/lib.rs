@@ -1,8 +1,14 @@
-use bevy::{app::PluginGroupBuilder, prelude::*, render::{settings::{PowerPreference, RenderCreation, WgpuSettings}, RenderPlugin}, window::{PresentMode, WindowMode, WindowResolution}};
+use std::{path::PathBuf, time::Duration};
+
+use bevy::{app::PluginGroupBuilder, prelude::*, render::{settings::{PowerPreference, RenderCreation, WgpuSettings}, RenderPlugin}, window::{PresentMode, PrimaryWindow, WindowMode, WindowResolution}};
 use bevy_embedded_assets::{EmbeddedAssetPlugin, PluginMode};
 use bevy_kira_audio::AudioPlugin;
 use bevy_lunex::UiLunexPlugin;
+use game_audio::ChannelVolumes;
+use game_cameras::ShowcaseCamera;
+use game_movies::SubtitleSettings;
 use vleue_kinetoscope::AnimatedImagePlugin;
+use serde::{Deserialize, Serialize};
 use clap::Parser;
 
 
@@ -27,8 +33,464 @@ pub struct Args {
 }
 
 
+/// Persistent user preferences for the Bevypunk game
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct GamePreferences {
+    /// Flag to skip the initial intro
+    pub skip_intro: bool,
+    /// Input related preferences
+    pub controls: ControlPreferences,
+    /// Window related preferences
+    pub window: WindowPreferences,
+    /// Rendering related preferences
+    pub graphics: GraphicsPreferences,
+    /// Audio volume preferences
+    pub audio: AudioPreferences,
+    /// Movie subtitle preferences
+    pub subtitles: SubtitlePreferences,
+}
+impl GamePreferences {
+    /// Location of the preferences file in the platform config directory
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("bevypunk").join("preferences.ron"))
+    }
+    /// Load the preferences from disk, falling back to defaults if missing or invalid
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else { return Self::default() };
+        let Ok(string) = std::fs::read_to_string(&path) else { return Self::default() };
+        ron::from_str(&string).unwrap_or_else(|error| {
+            warn!("Failed to parse preferences {}: {error}", path.display());
+            Self::default()
+        })
+    }
+    /// Write the preferences to disk
+    pub fn save(&self) -> std::io::Result<()> {
+        let Some(path) = Self::path() else { return Ok(()) };
+        if let Some(dir) = path.parent() { std::fs::create_dir_all(dir)?; }
+        let string = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).map_err(std::io::Error::other)?;
+        std::fs::write(path, string)
+    }
+    /// Layer the launch arguments over the preferences. Only flags that were passed override the stored values.
+    pub fn with_args(mut self, args: &Args) -> Self {
+        if args.skip_intro { self.skip_intro = true; }
+        if args.lowram { self.graphics.lowram = true; }
+        if args.powersaver { self.graphics.gpu = GpuPower::LowPower; }
+        if args.windowed { self.window.mode = DisplayMode::Windowed; }
+        self
+    }
+}
+
+/// Input related preferences
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct ControlPreferences {
+    /// Camera rotation speed in degrees per pixel
+    pub mouse_sensitivity: f32,
+    /// Invert the vertical camera rotation
+    pub invert_y: bool,
+}
+impl ControlPreferences {
+    /// Apply the control preferences to the camera
+    fn apply(&self, camera: &mut ShowcaseCamera) {
+        camera.mouse_sensitivity = self.mouse_sensitivity;
+        camera.invert_y = self.invert_y;
+    }
+    /// System for applying the control preferences to all showcase cameras when they change
+    fn system(preferences: Res<GamePreferences>, mut query: Query<&mut ShowcaseCamera>) {
+        for mut camera in &mut query {
+            preferences.controls.apply(&mut camera);
+        }
+    }
+    /// System for applying the control preferences to newly spawned showcase cameras
+    fn added(preferences: Res<GamePreferences>, mut query: Query<&mut ShowcaseCamera, Added<ShowcaseCamera>>) {
+        for mut camera in &mut query {
+            preferences.controls.apply(&mut camera);
+        }
+    }
+}
+impl Default for ControlPreferences {
+    fn default() -> Self {
+        Self {
+            mouse_sensitivity: 0.1,
+            invert_y: false,
+        }
+    }
+}
+
+/// Window related preferences
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct WindowPreferences {
+    /// How the window is presented on the monitor
+    pub mode: DisplayMode,
+    /// Logical window size used when windowed
+    pub resolution: (u32, u32),
+    /// Wait for the monitor refresh before presenting a frame
+    pub vsync: bool,
+    /// Index of the monitor to use, `None` keeps the current one
+    pub monitor: Option<usize>,
+}
+impl WindowPreferences {
+    /// Convert into the Bevy monitor selection
+    pub fn monitor_selection(&self) -> MonitorSelection {
+        match self.monitor {
+            Some(index) => MonitorSelection::Index(index),
+            None => MonitorSelection::Current,
+        }
+    }
+    /// Convert into the Bevy window mode
+    pub fn window_mode(&self) -> WindowMode {
+        match self.mode {
+            DisplayMode::Windowed => WindowMode::Windowed,
+            DisplayMode::Borderless => WindowMode::BorderlessFullscreen(self.monitor_selection()),
+            DisplayMode::Fullscreen => WindowMode::Fullscreen(self.monitor_selection(), VideoModeSelection::Current),
+        }
+    }
+    /// Convert into the Bevy present mode
+    pub fn present_mode(&self) -> PresentMode {
+        if self.vsync { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync }
+    }
+    /// Convert into the Bevy window resolution
+    pub fn window_resolution(&self) -> WindowResolution {
+        WindowResolution::new(self.resolution.0 as f32, self.resolution.1 as f32)
+    }
+    /// Convert into the Bevy window position
+    pub fn window_position(&self) -> WindowPosition {
+        match self.monitor {
+            Some(_) => WindowPosition::Centered(self.monitor_selection()),
+            None => WindowPosition::Automatic,
+        }
+    }
+    /// System for applying the window preferences to the primary window when they change
+    fn system(
+        mut commands: Commands,
+        preferences: Res<GamePreferences>,
+        pending: Option<Res<PendingWindowPreferences>>,
+        mut applied: ResMut<AppliedWindowPreferences>,
+        mut window: Single<&mut Window, With<PrimaryWindow>>,
+    ) {
+        let current = preferences.window;
+        let confirmed = std::mem::take(&mut applied.confirmed);
+        if applied.preferences == Some(current) { return; }
+
+        // Start the revert timer unless this change is the revert itself or was confirmed already
+        match (&pending, applied.preferences) {
+            _ if confirmed => {},
+            (Some(pending), _) if pending.previous == current => commands.remove_resource::<PendingWindowPreferences>(),
+            (Some(pending), _) => commands.insert_resource(PendingWindowPreferences::new(pending.previous)),
+            (None, Some(previous)) => commands.insert_resource(PendingWindowPreferences::new(previous)),
+            (None, None) => {},
+        }
+
+        // Apply the new settings
+        window.mode = current.window_mode();
+        window.present_mode = current.present_mode();
+        window.resolution.set(current.resolution.0 as f32, current.resolution.1 as f32);
+        if current.mode == DisplayMode::Windowed {
+            window.position = WindowPosition::Centered(current.monitor_selection());
+        }
+        applied.preferences = Some(current);
+    }
+}
+impl Default for WindowPreferences {
+    fn default() -> Self {
+        Self {
+            mode: DisplayMode::Borderless,
+            resolution: (1280, 720),
+            vsync: true,
+            monitor: None,
+        }
+    }
+}
+
+/// Window preferences applied at runtime that the player has not confirmed yet.
+/// When the timer runs out, the previous preferences are restored.
+#[derive(Resource, Debug, Clone)]
+pub struct PendingWindowPreferences {
+    /// The preferences to restore on revert
+    pub previous: WindowPreferences,
+    /// Time left to confirm the change
+    pub timer: Timer,
+}
+impl PendingWindowPreferences {
+    /// How long the player has to confirm the change
+    pub const TIMEOUT: Duration = Duration::from_secs(15);
+    /// Creates new instance
+    fn new(previous: WindowPreferences) -> Self {
+        Self {
+            previous,
+            timer: Timer::new(Self::TIMEOUT, TimerMode::Once),
+        }
+    }
+    /// Seconds left before the change is reverted
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
+    }
+    /// System for reverting the change once the timer runs out
+    fn system(mut commands: Commands, mut pending: ResMut<PendingWindowPreferences>, time: Res<Time>) {
+        if pending.timer.tick(time.delta()).just_finished() {
+            commands.trigger(RevertWindowPreferences);
+        }
+    }
+    /// Observer for confirming the pending change
+    fn keep(_: Trigger<KeepWindowPreferences>, mut commands: Commands, mut preferences: ResMut<GamePreferences>) {
+        commands.remove_resource::<PendingWindowPreferences>();
+        // Mark as changed so the confirmed preferences get saved
+        preferences.set_changed();
+    }
+    /// Observer for applying the next window preferences without asking to confirm them
+    fn confirm(_: Trigger<ConfirmWindowPreferences>, mut commands: Commands, mut applied: ResMut<AppliedWindowPreferences>) {
+        commands.remove_resource::<PendingWindowPreferences>();
+        applied.confirmed = true;
+    }
+    /// Observer for restoring the previous preferences
+    fn revert(_: Trigger<RevertWindowPreferences>, pending: Option<Res<PendingWindowPreferences>>, mut preferences: ResMut<GamePreferences>) {
+        if let Some(pending) = pending {
+            preferences.window = pending.previous;
+        }
+    }
+}
+
+/// Trigger this event to keep the [`PendingWindowPreferences`]
+#[derive(Event)]
+pub struct KeepWindowPreferences;
+
+/// Trigger this event to revert the [`PendingWindowPreferences`]
+#[derive(Event)]
+pub struct RevertWindowPreferences;
+
+/// Trigger this event right after changing the window preferences to apply them without the revert timer,
+/// for example when discarding the changes. Any [`PendingWindowPreferences`] are dropped.
+#[derive(Event)]
+pub struct ConfirmWindowPreferences;
+
+/// Window preferences last applied to the primary window
+#[derive(Resource, Default)]
+struct AppliedWindowPreferences {
+    preferences: Option<WindowPreferences>,
+    /// The next change was confirmed with [`ConfirmWindowPreferences`]
+    confirmed: bool,
+}
+
+/// How the window is presented on the monitor
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisplayMode {
+    /// Regular decorated window
+    Windowed,
+    /// Borderless window covering the whole monitor
+    Borderless,
+    /// Exclusive fullscreen
+    Fullscreen,
+}
+
+/// Rendering related preferences
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct GraphicsPreferences {
+    /// Which GPU should be preferred
+    pub gpu: GpuPower,
+    /// If to run with low ram expectations
+    pub lowram: bool,
+}
+impl GraphicsPreferences {
+    /// Convert into the wgpu power preference
+    pub fn power_preference(&self) -> PowerPreference {
+        match self.gpu {
+            GpuPower::HighPerformance => PowerPreference::HighPerformance,
+            GpuPower::LowPower => PowerPreference::LowPower,
+        }
+    }
+}
+impl Default for GraphicsPreferences {
+    fn default() -> Self {
+        Self {
+            gpu: GpuPower::HighPerformance,
+            lowram: false,
+        }
+    }
+}
+
+/// Which GPU should be preferred
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GpuPower {
+    /// Dedicated GPU if available
+    HighPerformance,
+    /// Integrated GPU if available
+    LowPower,
+}
+
+/// Audio volume preferences, all values are in range `0.0..=1.0`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct AudioPreferences {
+    /// Volume applied on top of every channel
+    pub master: f32,
+    /// Volume of the menu music
+    pub music: f32,
+    /// Volume of the sound effects
+    pub sfx: f32,
+    /// Volume of the interface sounds
+    pub ui: f32,
+    /// Volume of the voices and movie audio
+    pub voice: f32,
+}
+impl AudioPreferences {
+    /// System for applying the audio preferences to the channel volumes
+    fn system(preferences: Res<GamePreferences>, mut volumes: ResMut<ChannelVolumes>) {
+        let audio = preferences.audio;
+        volumes.set_if_neq(ChannelVolumes { master: audio.master, music: audio.music, sfx: audio.sfx, ui: audio.ui, voice: audio.voice });
+    }
+}
+impl Default for AudioPreferences {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            music: 0.8,
+            sfx: 1.0,
+            ui: 1.0,
+            voice: 1.0,
+        }
+    }
+}
+
+/// Movie subtitle preferences
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct SubtitlePreferences {
+    /// If the subtitles are shown
+    pub enabled: bool,
+    /// How large the subtitles are
+    pub size: SubtitleSize,
+}
+impl SubtitlePreferences {
+    /// System for applying the subtitle preferences to the subtitle layers
+    fn system(preferences: Res<GamePreferences>, mut settings: ResMut<SubtitleSettings>) {
+        let subtitles = preferences.subtitles;
+        settings.set_if_neq(SubtitleSettings { enabled: subtitles.enabled, size: subtitles.size.height() });
+    }
+}
+impl Default for SubtitlePreferences {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            size: SubtitleSize::Medium,
+        }
+    }
+}
+
+/// How large the subtitles are
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubtitleSize {
+    Small,
+    Medium,
+    Large,
+}
+impl SubtitleSize {
+    /// Height of the text in percent of the screen height
+    pub fn height(self) -> f32 {
+        match self {
+            SubtitleSize::Small => 3.0,
+            SubtitleSize::Medium => 4.0,
+            SubtitleSize::Large => 5.5,
+        }
+    }
+}
+
+
+/// Plugin which inserts [`GamePreferences`] and writes them back to disk when they change.
+#[derive(Clone)]
+pub struct PreferencesPlugin {
+    /// Launch arguments layered over the stored preferences
+    pub args: Args,
+    /// Preferences as they were stored on disk
+    pub stored: GamePreferences,
+}
+impl PreferencesPlugin {
+    /// Load the stored preferences from disk
+    pub fn load(args: Args) -> Self {
+        Self { args, stored: GamePreferences::load() }
+    }
+    /// The stored preferences with launch arguments applied
+    pub fn preferences(&self) -> GamePreferences {
+        self.stored.clone().with_args(&self.args)
+    }
+    /// Returns the preferences with the launch argument overrides removed, so they don't get persisted.
+    /// Values the player changed in the meantime are kept.
+    fn persisted(&self, preferences: &GamePreferences) -> GamePreferences {
+        let mut persisted = preferences.clone();
+        if self.args.skip_intro && persisted.skip_intro { persisted.skip_intro = self.stored.skip_intro; }
+        if self.args.lowram && persisted.graphics.lowram { persisted.graphics.lowram = self.stored.graphics.lowram; }
+        if self.args.powersaver && persisted.graphics.gpu == GpuPower::LowPower { persisted.graphics.gpu = self.stored.graphics.gpu; }
+        if self.args.windowed && persisted.window.mode == DisplayMode::Windowed { persisted.window.mode = self.stored.window.mode; }
+        persisted
+    }
+    /// System for scheduling a write when the preferences change, restarting the delay on every change
+    fn schedule(preferences: Res<GamePreferences>, mut debounce: ResMut<SaveDebounce>) {
+        // Skip the initial insert
+        if preferences.is_added() { return; }
+        debounce.0 = Some(Timer::new(SaveDebounce::DELAY, TimerMode::Once));
+    }
+    /// System for writing the preferences to disk once they stop changing or the app exits
+    fn save(
+        mut debounce: ResMut<SaveDebounce>,
+        time: Res<Time<Real>>,
+        exit: EventReader<AppExit>,
+        preferences: Res<GamePreferences>,
+        overrides: Res<LaunchOverrides>,
+        pending: Option<Res<PendingWindowPreferences>>,
+    ) {
+        let Some(timer) = &mut debounce.0 else { return };
+        if !timer.tick(time.delta()).finished() && exit.is_empty() { return; }
+        debounce.0 = None;
+
+        // Never persist window settings that were not confirmed yet
+        let mut preferences = preferences.clone();
+        if let Some(pending) = pending { preferences.window = pending.previous; }
+
+        if let Err(error) = overrides.0.persisted(&preferences).save() {
+            warn!("Failed to save preferences: {error}");
+        }
+    }
+}
+impl Plugin for PreferencesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.preferences());
+        app.insert_resource(LaunchOverrides(self.clone()));
+        app.init_resource::<ChannelVolumes>();
+        app.init_resource::<SubtitleSettings>();
+        app.init_resource::<SaveDebounce>();
+        app.init_resource::<AppliedWindowPreferences>();
+        app.add_systems(Last, (PreferencesPlugin::schedule.run_if(resource_changed::<GamePreferences>), PreferencesPlugin::save).chain());
+        app.add_systems(Update, (
+            ControlPreferences::system.run_if(resource_changed::<GamePreferences>),
+            ControlPreferences::added,
+            AudioPreferences::system.run_if(resource_changed::<GamePreferences>),
+            SubtitlePreferences::system.run_if(resource_changed::<GamePreferences>),
+            WindowPreferences::system.run_if(resource_changed::<GamePreferences>),
+            PendingWindowPreferences::system.run_if(resource_exists::<PendingWindowPreferences>),
+        ));
+        app.add_observer(PendingWindowPreferences::keep);
+        app.add_observer(PendingWindowPreferences::revert);
+        app.add_observer(PendingWindowPreferences::confirm);
+    }
+}
+
+/// Holds the launch arguments and stored preferences for the save system
+#[derive(Resource)]
+struct LaunchOverrides(PreferencesPlugin);
+
+/// Pending write of the preferences, so dragging a slider does not write the file on every step
+#[derive(Resource, Default)]
+struct SaveDebounce(Option<Timer>);
+impl SaveDebounce {
+    /// How long the preferences have to stay unchanged before they are written
+    const DELAY: Duration = Duration::from_millis(500);
+}
+
+
 /// Plugin group implementing minimal default logic.
-pub struct BevyPlugins(pub Args);
+pub struct BevyPlugins(pub GamePreferences);
 impl PluginGroup for BevyPlugins {
     fn build(self) -> PluginGroupBuilder {
         let mut builder = PluginGroupBuilder::start::<Self>();
@@ -43,9 +505,10 @@ impl PluginGroup for BevyPlugins {
         builder = builder.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "Bevypunk".into(),
-                mode: if self.0.windowed { WindowMode::Windowed } else { WindowMode::BorderlessFullscreen(MonitorSelection::Current) },
-                present_mode: PresentMode::AutoVsync,
-                resolution: WindowResolution::new(1280.0, 720.0),
+                mode: self.0.window.window_mode(),
+                present_mode: self.0.window.present_mode(),
+                resolution: self.0.window.window_resolution(),
+                position: self.0.window.window_position(),
                 ..default()
             }),
             ..default()
@@ -55,7 +518,7 @@ impl PluginGroup for BevyPlugins {
         builder = builder.set(RenderPlugin {
             render_creation: RenderCreation::Automatic(
                 WgpuSettings {
-                    power_preference: if !self.0.powersaver { PowerPreference::HighPerformance } else { PowerPreference::LowPower },
+                    power_preference: self.0.graphics.power_preference(),
                     ..default()
                 }
             ),
@@ -63,7 +526,7 @@ impl PluginGroup for BevyPlugins {
         });
 
         // Disable the buildin pointers
-        builder = builder.set(PointerInputPlugin { 
+        builder = builder.set(PointerInputPlugin {
             is_mouse_enabled: false,
             is_touch_enabled: false,
         });
@@ -75,3 +538,74 @@ impl PluginGroup for BevyPlugins {
         builder
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+    use super::*;
+
+    /// App with only the window preference systems and a primary window
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.init_resource::<GamePreferences>();
+        app.init_resource::<AppliedWindowPreferences>();
+        app.add_systems(Update, (
+            WindowPreferences::system.run_if(resource_changed::<GamePreferences>),
+            PendingWindowPreferences::system.run_if(resource_exists::<PendingWindowPreferences>),
+        ));
+        app.add_observer(PendingWindowPreferences::keep);
+        app.add_observer(PendingWindowPreferences::revert);
+        app.add_observer(PendingWindowPreferences::confirm);
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        app.update();
+        app
+    }
+
+    /// Run the app until the revert timeout has passed
+    fn wait_for_timeout(app: &mut App) {
+        let frames = (PendingWindowPreferences::TIMEOUT + Duration::from_secs(1)).as_millis() / 100;
+        for _ in 0..frames { app.update(); }
+    }
+
+    #[test]
+    fn discard_after_keep_does_not_revert() {
+        let mut app = app();
+        let original = app.world().resource::<GamePreferences>().window;
+
+        // Change the resolution and keep it
+        app.world_mut().resource_mut::<GamePreferences>().window.resolution = (1920, 1080);
+        app.update();
+        assert!(app.world().contains_resource::<PendingWindowPreferences>());
+        app.world_mut().trigger(KeepWindowPreferences);
+        app.update();
+        assert!(!app.world().contains_resource::<PendingWindowPreferences>());
+
+        // Discard the changes made on the settings screen
+        app.world_mut().resource_mut::<GamePreferences>().window = original;
+        app.world_mut().trigger(ConfirmWindowPreferences);
+        app.update();
+        assert!(!app.world().contains_resource::<PendingWindowPreferences>());
+
+        // Nothing brings the discarded resolution back once the timeout passes
+        wait_for_timeout(&mut app);
+        assert_eq!(app.world().resource::<GamePreferences>().window, original);
+        let mut windows = app.world_mut().query_filtered::<&Window, With<PrimaryWindow>>();
+        let window = windows.single(app.world()).unwrap();
+        assert_eq!((window.resolution.width(), window.resolution.height()), (1280.0, 720.0));
+    }
+
+    #[test]
+    fn unconfirmed_change_reverts() {
+        let mut app = app();
+        let original = app.world().resource::<GamePreferences>().window;
+
+        app.world_mut().resource_mut::<GamePreferences>().window.resolution = (1920, 1080);
+        app.update();
+        wait_for_timeout(&mut app);
+        assert_eq!(app.world().resource::<GamePreferences>().window, original);
+        assert!(!app.world().contains_resource::<PendingWindowPreferences>());
+    }
+}
@@ -0,0 +1,384 @@
+use std::{collections::{BTreeMap, BTreeSet}, path::PathBuf, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use bevy::prelude::*;
+use game_character::CharacterAppearance;
+use serde::{Deserialize, Serialize};
+
+
+/// Current version of the save file schema
+pub const SAVE_VERSION: u32 = 1;
+
+/// Function migrating the raw save data one version up
+pub type Migration = fn(&mut ron::Map) -> Result<(), SaveError>;
+
+/// Data stored in a save slot
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct SaveData {
+    /// Version of the schema this data was written with
+    pub version: u32,
+    /// Player facing name of the save
+    pub name: String,
+    /// Appearance of the player character
+    pub character: CharacterAppearance,
+    /// Total time spent in the game
+    pub playtime: Duration,
+    /// Seconds since the Unix epoch when the save was written
+    pub timestamp: u64,
+    /// Name of the state the game should resume into
+    pub state: String,
+}
+impl SaveData {
+    /// Creates new instance
+    pub fn new(name: impl std::fmt::Display, character: CharacterAppearance, state: impl std::fmt::Display) -> Self {
+        Self {
+            name: name.to_string(),
+            character,
+            state: state.to_string(),
+            ..Default::default()
+        }
+    }
+}
+impl Default for SaveData {
+    fn default() -> Self {
+        Self {
+            version: SAVE_VERSION,
+            name: String::new(),
+            character: CharacterAppearance::default(),
+            playtime: Duration::ZERO,
+            timestamp: 0,
+            state: String::new(),
+        }
+    }
+}
+
+/// Save data together with the slot it was read from
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveSlot {
+    /// Identifier of the slot, used as the file name
+    pub id: String,
+    /// The stored data
+    pub data: SaveData,
+}
+
+/// Errors that can happen while working with saves
+#[derive(Debug)]
+pub enum SaveError {
+    /// The platform has no writable data directory
+    NoStorage,
+    /// The slot does not exist
+    NotFound(String),
+    /// The save was written by a newer version of the game or a migration is missing
+    UnsupportedVersion(u32),
+    /// Reading or writing the file failed
+    Io(std::io::Error),
+    /// The file is not valid save data
+    Parse(ron::error::SpannedError),
+    /// The data could not be converted
+    Ron(ron::Error),
+}
+impl std::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::NoStorage => write!(f, "no storage available for saves"),
+            SaveError::NotFound(slot) => write!(f, "save slot {slot} does not exist"),
+            SaveError::UnsupportedVersion(version) => write!(f, "unsupported save version {version}"),
+            SaveError::Io(error) => write!(f, "{error}"),
+            SaveError::Parse(error) => write!(f, "{error}"),
+            SaveError::Ron(error) => write!(f, "{error}"),
+        }
+    }
+}
+impl std::error::Error for SaveError {}
+impl From<std::io::Error> for SaveError {
+    fn from(error: std::io::Error) -> Self {
+        SaveError::Io(error)
+    }
+}
+impl From<ron::error::SpannedError> for SaveError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        SaveError::Parse(error)
+    }
+}
+impl From<ron::Error> for SaveError {
+    fn from(error: ron::Error) -> Self {
+        SaveError::Ron(error)
+    }
+}
+
+/// Only the version of the save, read before the full data to pick migrations
+#[derive(Deserialize)]
+struct SaveHeader {
+    #[serde(default)]
+    version: u32,
+}
+
+
+/// Access to the save slots on disk.
+/// Writes are atomic and keep the previous file as a backup, which is used if the main file gets corrupted.
+#[derive(Resource, Clone)]
+pub struct SaveStore {
+    /// Directory holding the save files, `None` if saving is not supported
+    pub dir: Option<PathBuf>,
+    /// Migrations keyed by the version they upgrade from
+    migrations: BTreeMap<u32, Migration>,
+}
+impl SaveStore {
+    /// File extension of the save files
+    const EXTENSION: &'static str = "ron";
+    /// Creates new instance storing the saves in the given directory
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: Some(dir.into()),
+            migrations: BTreeMap::new(),
+        }
+    }
+    /// Creates new instance storing the saves in the platform data directory
+    pub fn platform() -> Self {
+        Self {
+            dir: dirs::data_dir().map(|dir| dir.join("bevypunk").join("saves")),
+            migrations: BTreeMap::new(),
+        }
+    }
+    /// Register a migration upgrading the data from the given version to the next one.
+    pub fn migration(mut self, from: u32, migration: Migration) -> Self {
+        self.migrations.insert(from, migration);
+        self
+    }
+    /// Path of the save file for the slot
+    fn path(&self, slot: &str) -> Result<PathBuf, SaveError> {
+        let dir = self.dir.as_ref().ok_or(SaveError::NoStorage)?;
+        Ok(dir.join(slot).with_extension(Self::EXTENSION))
+    }
+    /// Path of the backup file for the slot
+    fn backup_path(&self, slot: &str) -> Result<PathBuf, SaveError> {
+        Ok(self.path(slot)?.with_extension("bak"))
+    }
+    /// Parse the save data, running the migrations if the version is older
+    fn parse(&self, string: &str) -> Result<SaveData, SaveError> {
+        let header: SaveHeader = ron::from_str(string)?;
+        if header.version == SAVE_VERSION { return Ok(ron::from_str(string)?); }
+        if header.version > SAVE_VERSION { return Err(SaveError::UnsupportedVersion(header.version)); }
+
+        let ron::Value::Map(mut map) = string.parse::<ron::Value>()? else { return Err(SaveError::UnsupportedVersion(header.version)) };
+        for version in header.version..SAVE_VERSION {
+            let migration = self.migrations.get(&version).ok_or(SaveError::UnsupportedVersion(version))?;
+            migration(&mut map)?;
+        }
+        map.insert("version", SAVE_VERSION);
+        Ok(ron::Value::Map(map).into_rust()?)
+    }
+    /// Read the slot, falling back to the backup if the main file is missing or unreadable
+    pub fn load(&self, slot: &str) -> Result<SaveData, SaveError> {
+        let path = self.path(slot)?;
+        let backup = self.backup_path(slot)?;
+        if !path.exists() {
+            if !backup.exists() { return Err(SaveError::NotFound(slot.to_string())); }
+            warn!("Save {slot} is missing, using backup");
+            return self.parse(&std::fs::read_to_string(backup)?);
+        }
+        match std::fs::read_to_string(&path).map_err(SaveError::from).and_then(|string| self.parse(&string)) {
+            Ok(data) => Ok(data),
+            Err(error) => {
+                warn!("Failed to load save {slot}: {error}, trying backup");
+                let string = std::fs::read_to_string(backup).map_err(|_| error)?;
+                self.parse(&string)
+            }
+        }
+    }
+    /// Write the data into the slot with the current timestamp
+    pub fn save(&self, slot: &str, data: &SaveData) -> Result<(), SaveError> {
+        let path = self.path(slot)?;
+        if let Some(dir) = path.parent() { std::fs::create_dir_all(dir)?; }
+
+        let mut data = data.clone();
+        data.version = SAVE_VERSION;
+        data.timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let string = ron::ser::to_string_pretty(&data, ron::ser::PrettyConfig::default())?;
+
+        // Write into a temporary file first, so a crash never leaves a half written save.
+        // The main file is copied to the backup and then replaced by a single rename, so it always exists.
+        let temp = path.with_extension("tmp");
+        std::fs::write(&temp, string)?;
+        if path.exists() { std::fs::copy(&path, self.backup_path(slot)?)?; }
+        std::fs::rename(&temp, &path)?;
+        Ok(())
+    }
+    /// Remove the slot together with its backup
+    pub fn delete(&self, slot: &str) -> Result<(), SaveError> {
+        let path = self.path(slot)?;
+        let backup = self.backup_path(slot)?;
+        if !path.exists() && !backup.exists() { return Err(SaveError::NotFound(slot.to_string())); }
+        if path.exists() { std::fs::remove_file(path)?; }
+        if backup.exists() { std::fs::remove_file(backup)?; }
+        Ok(())
+    }
+    /// All readable slots, newest first. Slots with only a backup left are included.
+    pub fn slots(&self) -> Vec<SaveSlot> {
+        let Some(Ok(entries)) = self.dir.as_ref().map(std::fs::read_dir) else { return Vec::new() };
+        let ids: BTreeSet<String> = entries.flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == Self::EXTENSION || extension == "bak"))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+        let mut slots: Vec<SaveSlot> = ids.into_iter()
+            .filter_map(|id| {
+                match self.load(&id) {
+                    Ok(data) => Some(SaveSlot { id, data }),
+                    Err(error) => { warn!("Skipping save {id}: {error}"); None },
+                }
+            })
+            .collect();
+        slots.sort_by(|a, b| b.data.timestamp.cmp(&a.data.timestamp));
+        slots
+    }
+    /// The most recently written slot
+    pub fn newest(&self) -> Option<SaveSlot> {
+        self.slots().into_iter().next()
+    }
+    /// Unused slot identifier
+    pub fn next_id(&self) -> String {
+        let next = self.slots().iter()
+            .filter_map(|slot| slot.id.strip_prefix("slot_")?.parse::<u32>().ok())
+            .max().map_or(1, |last| last + 1);
+        format!("slot_{next}")
+    }
+}
+
+
+/// The save the player is currently playing. Its playtime is counted by [`ActiveSave::playtime`].
+#[derive(Resource, Debug, Clone)]
+pub struct ActiveSave {
+    /// Slot the data belongs to
+    pub slot: String,
+    /// The data, written back on [`ActiveSave::store`]
+    pub data: SaveData,
+}
+impl ActiveSave {
+    /// Write the active data into its slot
+    pub fn store(&self, store: &SaveStore) -> Result<(), SaveError> {
+        store.save(&self.slot, &self.data)
+    }
+    /// How often the active save is written back
+    const AUTOSAVE: Duration = Duration::from_secs(60);
+    /// System for counting the playtime, schedule it in the gameplay states so menus and pauses are not counted
+    pub fn playtime(mut active: ResMut<ActiveSave>, time: Res<Time<Virtual>>) {
+        active.data.playtime += time.delta();
+    }
+    /// System for writing the active save back periodically and when the app exits
+    fn autosave(
+        active: Res<ActiveSave>,
+        store: Res<SaveStore>,
+        time: Res<Time<Real>>,
+        exit: EventReader<AppExit>,
+        mut timer: Local<Option<Timer>>,
+    ) {
+        let timer = timer.get_or_insert_with(|| Timer::new(ActiveSave::AUTOSAVE, TimerMode::Repeating));
+        if !timer.tick(time.delta()).just_finished() && exit.is_empty() { return; }
+        if let Err(error) = active.store(&store) {
+            warn!("Failed to autosave {}: {error}", active.slot);
+        }
+    }
+}
+
+
+/// Plugin providing the [`SaveStore`] and autosaving the [`ActiveSave`]
+pub struct SavePlugin;
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SaveStore::platform());
+        app.add_systems(Last, ActiveSave::autosave.run_if(resource_exists::<ActiveSave>));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Store in an empty temporary directory unique to the test
+    fn store(test: &str) -> SaveStore {
+        let dir = std::env::temp_dir().join(format!("bevypunk_save_{}_{test}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        SaveStore::new(dir)
+    }
+
+    #[test]
+    fn save_and_load() {
+        let store = store("save_and_load");
+        let data = SaveData::new("V", CharacterAppearance::default(), "NewGame");
+        store.save("slot_1", &data).unwrap();
+        let loaded = store.load("slot_1").unwrap();
+        assert_eq!(loaded.name, "V");
+        assert_eq!(loaded.state, "NewGame");
+        assert_eq!(loaded.version, SAVE_VERSION);
+    }
+
+    #[test]
+    fn migrates_old_versions() {
+        let store = store("migrates_old_versions").migration(0, |map| {
+            // Version 0 called the name a title
+            let title = map.remove(&ron::Value::String("title".to_string())).unwrap_or(ron::Value::String(String::new()));
+            map.insert("name", title);
+            Ok(())
+        });
+        let dir = store.dir.clone().unwrap();
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("slot_1.ron"), r#"(version: 0, title: "Old save")"#).unwrap();
+
+        let loaded = store.load("slot_1").unwrap();
+        assert_eq!(loaded.name, "Old save");
+        assert_eq!(loaded.version, SAVE_VERSION);
+    }
+
+    #[test]
+    fn rejects_missing_migration_and_newer_versions() {
+        let store = store("rejects_versions");
+        let dir = store.dir.clone().unwrap();
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("old.ron"), "(version: 0)").unwrap();
+        std::fs::write(dir.join("new.ron"), format!("(version: {})", SAVE_VERSION + 1)).unwrap();
+
+        assert!(matches!(store.load("old"), Err(SaveError::UnsupportedVersion(0))));
+        assert!(matches!(store.load("new"), Err(SaveError::UnsupportedVersion(version)) if version == SAVE_VERSION + 1));
+    }
+
+    #[test]
+    fn falls_back_to_backup() {
+        let store = store("falls_back_to_backup");
+        store.save("slot_1", &SaveData::new("First", CharacterAppearance::default(), "NewGame")).unwrap();
+        store.save("slot_1", &SaveData::new("Second", CharacterAppearance::default(), "NewGame")).unwrap();
+        let path = store.path("slot_1").unwrap();
+
+        // Corrupted main file
+        std::fs::write(&path, "not a save").unwrap();
+        assert_eq!(store.load("slot_1").unwrap().name, "First");
+
+        // Missing main file
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(store.load("slot_1").unwrap().name, "First");
+        assert_eq!(store.slots().len(), 1);
+    }
+
+    #[test]
+    fn next_id_follows_highest_slot() {
+        let store = store("next_id");
+        assert_eq!(store.next_id(), "slot_1");
+        let data = SaveData::new("V", CharacterAppearance::default(), "NewGame");
+        store.save("slot_1", &data).unwrap();
+        store.save("slot_7", &data).unwrap();
+        store.save("custom", &data).unwrap();
+        assert_eq!(store.next_id(), "slot_8");
+    }
+
+    #[test]
+    fn delete_removes_slot() {
+        let store = store("delete_removes_slot");
+        let data = SaveData::new("V", CharacterAppearance::default(), "NewGame");
+        store.save("slot_1", &data).unwrap();
+        store.save("slot_1", &data).unwrap();
+        store.delete("slot_1").unwrap();
+        assert!(matches!(store.load("slot_1"), Err(SaveError::NotFound(_))));
+        assert!(store.slots().is_empty());
+    }
+}
@@ -0,0 +1,1225 @@
+use std::{collections::HashMap, sync::atomic::{AtomicU64, Ordering}, time::Duration};
+
+use bevy::{ecs::system::SystemParam, input::mouse::MouseMotion, prelude::*, sprite::Anchor, window::SystemCursorIcon};
+use bevy_kira_audio::prelude::*;
+use bevy_lunex::*;
+use game_audio::{decibels, UiChannel};
+use game_vfx::*;
+
+
+/// Font used by all widgets
+const FONT: &str = "fonts/rajdhani/Rajdhani-Medium.ttf";
+/// Font used by widget values
+const FONT_VALUE: &str = "fonts/rajdhani/Rajdhani-SemiBold.ttf";
+
+/// Returns the index `step` positions away from `index`, wrapping around `len`
+pub fn cycle_index(index: usize, len: usize, step: isize) -> usize {
+    if len == 0 { return 0; }
+    (index as isize + step).rem_euclid(len as isize) as usize
+}
+
+/// Sliced sprite used as a background of the widgets
+fn sliced(asset_server: &AssetServer, image: &str) -> Sprite {
+    Sprite {
+        image: asset_server.load(image.to_string()),
+        // Here we enable sprite slicing
+        image_mode: SpriteImageMode::Sliced(TextureSlicer { border: BorderRect::all(32.0), ..default() }),
+        ..default()
+    }
+}
+
+/// Spawns the static label box used by the option widgets
+fn spawn_label(ui: &mut ChildSpawnerCommands, asset_server: &AssetServer, layout: UiLayout, text: &str) {
+    ui.spawn((
+        layout,
+        UiColor::from(Color::BEVYPUNK_RED.with_alpha(0.15)),
+        sliced(asset_server, "images/ui/components/button_symetric_sliced.png"),
+        Pickable::IGNORE,
+    )).with_children(|ui| {
+        ui.spawn((
+            // For text always use window layout to position it
+            UiLayout::window().pos((Rh(40.0), Rl(50.0))).anchor(Anchor::CenterLeft).pack(),
+            UiColor::from(Color::BEVYPUNK_RED),
+            UiTextSize::from(Rh(50.0)),
+            Text2d::new(text.to_ascii_uppercase()),
+            TextFont {
+                font: asset_server.load(FONT),
+                font_size: 64.0,
+                ..default()
+            },
+            Pickable::IGNORE,
+        ));
+    });
+}
+
+/// Spawns a clickable chevron, the `side` is either `"left"` or `"right"`
+fn spawn_chevron<'a>(ui: &'a mut ChildSpawnerCommands, asset_server: &AssetServer, layout: UiLayout, side: &str) -> EntityCommands<'a> {
+    let mut chevron = ui.spawn((
+        Name::new(format!("Chevron {side}")),
+        layout,
+        OnHoverSetCursor::new(SystemCursorIcon::Pointer),
+        Focusable::new(),
+    ));
+    chevron.with_children(|ui| {
+        ui.spawn((
+            UiLayout::window().full().pack(),
+            UiHover::new().instant(true),
+            UiColor::new(vec![
+                (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.15)),
+                (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
+            ]),
+            sliced(asset_server, &format!("images/ui/components/button_sliced_bottom_{side}.png")),
+            Pickable::IGNORE,
+        )).with_children(|ui| {
+            ui.spawn((
+                UiLayout::window().pos(Rl((50.0, 50.0))).anchor(Anchor::Center).size(Rh(65.0)).pack(),
+                Sprite::from_image(asset_server.load(format!("images/ui/components/chevron_{side}.png"))),
+                UiHover::new().forward_speed(20.0).backward_speed(20.0).curve(|v| v.round()),
+                UiColor::new(vec![
+                    (UiBase::id(), Color::BEVYPUNK_RED),
+                    (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
+                ]),
+                Pickable::IGNORE,
+            ));
+        });
+    }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>);
+    chevron
+}
+
+/// Spawns the text displaying the widget value
+fn spawn_value<C: Component>(ui: &mut ChildSpawnerCommands, asset_server: &AssetServer, layout: UiLayout, text: String, marker: C) {
+    ui.spawn((
+        layout,
+        UiColor::from(Color::BEVYPUNK_BLUE),
+        UiTextSize::from(Rh(50.0)),
+        Text2d::new(text),
+        TextFont {
+            font: asset_server.load(FONT_VALUE),
+            font_size: 64.0,
+            ..default()
+        },
+        Pickable::IGNORE,
+        marker,
+    ));
+}
+
+
+
+/// Hover button with a sliced background and text. Triggers [`ButtonPressed`] when clicked.
+#[derive(Component, Clone)]
+pub struct MenuButton {
+    pub text: String,
+    pub image: String,
+    pub layouts: Option<(UiLayoutType, UiLayoutType)>,
+    pub fluff: Option<String>,
+    pub animated: bool,
+    pub centered: bool,
+    pub disabled: bool,
+}
+impl MenuButton {
+    /// Creates new instance
+    pub fn new(text: impl std::fmt::Display) -> Self {
+        Self {
+            text: text.to_string(),
+            image: "images/ui/components/button_symetric_sliced.png".to_string(),
+            layouts: None,
+            fluff: None,
+            animated: false,
+            centered: false,
+            disabled: false,
+        }
+    }
+    /// Replace the default sliced background image with a new one.
+    pub fn image(mut self, image: impl std::fmt::Display) -> Self {
+        self.image = image.to_string();
+        self
+    }
+    /// Set the layouts of the background for the base and hover state.
+    pub fn layouts(mut self, base: impl Into<UiLayoutType>, hover: impl Into<UiLayoutType>) -> Self {
+        self.layouts = Some((base.into(), hover.into()));
+        self
+    }
+    /// Add a small decorative text on the right side.
+    pub fn fluff(mut self, fluff: impl std::fmt::Display) -> Self {
+        self.fluff = Some(fluff.to_string());
+        self
+    }
+    /// Reveal the text with decryption animation.
+    pub fn animated(mut self) -> Self {
+        self.animated = true;
+        self
+    }
+    /// Center the text instead of aligning it to the left.
+    pub fn centered(mut self) -> Self {
+        self.centered = true;
+        self
+    }
+    /// Render the button dimmed, without hover and ignoring clicks.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+    /// Spawn the button with the given layout.
+    pub fn spawn<'a>(self, ui: &'a mut ChildSpawnerCommands, asset_server: &AssetServer, layout: UiLayout) -> EntityCommands<'a> {
+        let (base, hover) = self.layouts.clone().unwrap_or_else(|| (UiLayout::window().full().into(), UiLayout::window().full().into()));
+        let text = self.text.to_ascii_uppercase();
+        // Disabled buttons stay in the base state, so only the base colors are dimmed
+        let dim = if self.disabled { 0.35 } else { 1.0 };
+        let mut button = ui.spawn((
+            Name::new(self.text.clone()),
+            layout,
+            self.clone(),
+        ));
+        button.with_children(|ui| {
+
+            // Spawn the image
+            ui.spawn((
+                // You can define layouts for multiple states
+                UiLayout::new(vec![
+                    (UiBase::id(), base),
+                    (UiHover::id(), hover)
+                ]),
+                // Like this you can enable a state
+                UiHover::new().forward_speed(20.0).backward_speed(4.0),
+                // You can specify colors for multiple states
+                UiColor::new(vec![
+                    (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.15 * dim)),
+                    (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
+                ]),
+                sliced(asset_server, &self.image),
+                // Make sure it does not cover the bounding zone of parent
+                Pickable::IGNORE,
+            )).with_children(|ui| {
+
+                // Spawn the text
+                let mut label = ui.spawn((
+                    // For text always use window layout to position it
+                    if self.centered {
+                        UiLayout::window().pos(Rl(50.0)).anchor(Anchor::Center).pack()
+                    } else {
+                        UiLayout::window().pos((Rh(40.0), Rl(50.0))).anchor(Anchor::CenterLeft).pack()
+                    },
+                    UiColor::new(vec![
+                        (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(dim)),
+                        (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
+                    ]),
+                    UiHover::new().forward_speed(20.0).backward_speed(4.0),
+                    // You can control the size of the text
+                    UiTextSize::from(Rh(60.0)),
+                    TextFont {
+                        font: asset_server.load(FONT),
+                        font_size: 64.0,
+                        ..default()
+                    },
+                    // Make sure it does not cover the bounding zone of parent
+                    Pickable::IGNORE,
+                ));
+                if self.animated {
+                    label.insert((Text2d::default(), TextAnimator::new(text).function(decryption_animation).duration(1.0)));
+                } else {
+                    label.insert(Text2d::new(text));
+                }
+
+                // Spawn the fluff
+                if let Some(fluff) = &self.fluff {
+                    ui.spawn((
+                        UiLayout::window().pos(Rl((90.0, 50.0))).anchor(Anchor::CenterRight).pack(),
+                        UiColor::new(vec![
+                            (UiBase::id(), Color::BEVYPUNK_BLUE.with_alpha(0.2 * dim)),
+                            (UiHover::id(), Color::BEVYPUNK_YELLOW.with_alpha(1.2))
+                        ]),
+                        UiHover::new().forward_speed(20.0).backward_speed(4.0),
+                        UiTextSize::from(Rh(60.0)),
+                        Text2d::new(fluff),
+                        TextFont {
+                            font: asset_server.load("fonts/rajdhani/Rajdhani-Bold.ttf"),
+                            font_size: 64.0,
+                            ..default()
+                        },
+                        Pickable::IGNORE,
+                    ));
+                }
+            });
+
+        });
+
+        // Let the player know the button does nothing
+        if self.disabled {
+            button.observe(|_: Trigger<Pointer<Click>>, mut commands: Commands| commands.trigger(UiSound::Error) );
+            return button;
+        }
+
+        // Enable the transition on hover
+        button.insert((OnHoverSetCursor::new(SystemCursorIcon::Pointer), Focusable::new()))
+        .observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>)
+        .observe(|trigger: Trigger<Activated>, mut commands: Commands| {
+            commands.trigger_targets(ButtonPressed, trigger.target());
+        });
+        button
+    }
+}
+
+/// Triggered on a [`MenuButton`] when it is pressed
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ButtonPressed;
+
+
+
+/// Label with a value between two chevrons. Triggers [`SelectorChanged`] when a chevron is clicked.
+/// If `options` are provided, the selector cycles through them. Otherwise the displayed `text` is
+/// expected to be updated by the user in reaction to the event.
+#[derive(Component, Reflect, Clone, PartialEq, Debug, Default)]
+pub struct ChevronSelector {
+    pub label: String,
+    pub options: Vec<String>,
+    pub index: usize,
+    pub text: String,
+    pub vertical: bool,
+}
+impl ChevronSelector {
+    /// Creates new instance
+    pub fn new(label: impl std::fmt::Display) -> Self {
+        Self {
+            label: label.to_string(),
+            ..Default::default()
+        }
+    }
+    /// Set the options to cycle through.
+    pub fn options(mut self, options: impl IntoIterator<Item = impl std::fmt::Display>) -> Self {
+        self.options = options.into_iter().map(|option| option.to_string()).collect();
+        self.text = self.options.get(self.index).cloned().unwrap_or_default();
+        self
+    }
+    /// Set the selected option.
+    pub fn index(mut self, index: usize) -> Self {
+        self.index = index;
+        self.text = self.options.get(self.index).cloned().unwrap_or(self.text);
+        self
+    }
+    /// Set the displayed value.
+    pub fn text(mut self, text: impl std::fmt::Display) -> Self {
+        self.text = text.to_string();
+        self
+    }
+    /// Place the chevrons under the label instead of next to it.
+    pub fn vertical(mut self) -> Self {
+        self.vertical = true;
+        self
+    }
+    /// Move the selection `step` positions away, wrapping around.
+    pub fn step(&mut self, step: isize) {
+        if self.options.is_empty() { return; }
+        self.index = cycle_index(self.index, self.options.len(), step);
+        self.text = self.options[self.index].clone();
+    }
+    /// Spawn the selector with the given layout.
+    pub fn spawn<'a>(self, ui: &'a mut ChildSpawnerCommands, asset_server: &AssetServer, layout: UiLayout) -> EntityCommands<'a> {
+        let mut selector = ui.spawn((
+            Name::new(self.label.clone()),
+            layout,
+            self.clone(),
+        ));
+        let entity = selector.id();
+        selector.with_children(|ui| {
+
+            // Horizontal or vertical arrangement of the parts
+            let (label, left, right, value) = if self.vertical {(
+                UiLayout::window().size(Rl((100.0, 60.0))).pack(),
+                UiLayout::window().y(Rl(65.0)).size(Rl((20.0, 35.0))).pack(),
+                UiLayout::window().x(Rl(80.0)).y(Rl(65.0)).size(Rl((20.0, 35.0))).pack(),
+                UiLayout::window().pos(Rl((50.0, 82.5))).anchor(Anchor::Center).pack(),
+            )} else {(
+                UiLayout::window().size(Rl((55.0, 100.0))).pack(),
+                UiLayout::window().x(Rl(57.0)).size(Rl((10.0, 100.0))).pack(),
+                UiLayout::window().x(Rl(90.0)).size(Rl((10.0, 100.0))).pack(),
+                UiLayout::window().pos(Rl((78.5, 50.0))).anchor(Anchor::Center).pack(),
+            )};
+
+            spawn_label(ui, asset_server, label, &self.label);
+            spawn_value(ui, asset_server, value, self.text.clone(), SelectorText);
+
+            // Spawn the chevrons
+            for (layout, side, step) in [(left, "left", -1), (right, "right", 1)] {
+                spawn_chevron(ui, asset_server, layout, side)
+                    .observe(move |_: Trigger<Activated>, mut commands: Commands, mut query: Query<&mut ChevronSelector>| {
+                        let Ok(mut selector) = query.get_mut(entity) else { return };
+                        selector.step(step);
+                        commands.trigger_targets(SelectorChanged { index: selector.index, step }, entity);
+                    });
+            }
+        });
+        selector
+    }
+    /// System for updating the displayed value when the selector changes
+    fn system(
+        mut commands: Commands,
+        query: Query<(Entity, &ChevronSelector), Changed<ChevronSelector>>,
+        children: Query<&Children>,
+        mut texts: Query<&mut Text2d, With<SelectorText>>,
+    ) {
+        for (entity, selector) in &query {
+            for descendant in children.iter_descendants(entity) {
+                if let Ok(mut text) = texts.get_mut(descendant) {
+                    if text.0 != selector.text { text.0 = selector.text.clone(); }
+                }
+            }
+            commands.trigger(RecomputeUiLayout);
+        }
+    }
+}
+
+/// Marker for the value text of [`ChevronSelector`]
+#[derive(Component)]
+struct SelectorText;
+
+/// Triggered on a [`ChevronSelector`] when one of the chevrons is clicked
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SelectorChanged {
+    /// The new selected option
+    pub index: usize,
+    /// Which chevron was clicked, `-1` for left and `1` for right
+    pub step: isize,
+}
+
+
+
+/// Row of tabs with chevrons on the sides. Triggers [`TabSelected`] when the selection changes.
+#[derive(Component, Reflect, Clone, PartialEq, Debug, Default)]
+pub struct TabBar {
+    pub tabs: Vec<String>,
+    pub selected: usize,
+}
+impl TabBar {
+    /// Creates new instance
+    pub fn new(tabs: impl IntoIterator<Item = impl std::fmt::Display>) -> Self {
+        Self {
+            tabs: tabs.into_iter().map(|tab| tab.to_string()).collect(),
+            selected: 0,
+        }
+    }
+    /// Set the selected tab.
+    pub fn selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+    /// Spawn the tab bar with the given layout.
+    pub fn spawn<'a>(self, ui: &'a mut ChildSpawnerCommands, asset_server: &AssetServer, layout: UiLayout) -> EntityCommands<'a> {
+        let mut bar = ui.spawn((
+            Name::new("Tab Bar"),
+            layout,
+            self.clone(),
+        ));
+        let entity = bar.id();
+        bar.with_children(|ui| {
+
+            // Spawn the chevrons
+            for (x, side, step) in [(5.0, "left", -1), (95.0, "right", 1)] {
+                ui.spawn((
+                    Name::new(format!("Chevron {side}")),
+                    UiLayout::window().pos(Rl((x, 50.0))).anchor(Anchor::Center).size(Rh(35.0)).pack(),
+                    Sprite::from_image(asset_server.load(format!("images/ui/components/chevron_{side}.png"))),
+                    UiHover::new().instant(true),
+                    UiColor::new(vec![
+                        (UiBase::id(), Color::BEVYPUNK_RED),
+                        (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
+                    ]),
+                    OnHoverSetCursor::new(SystemCursorIcon::Pointer),
+                    Focusable::new(),
+                )).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>)
+                .observe(move |_: Trigger<Activated>, mut commands: Commands, mut query: Query<&mut TabBar>| {
+                    let Ok(mut bar) = query.get_mut(entity) else { return };
+                    bar.selected = cycle_index(bar.selected, bar.tabs.len(), step);
+                    commands.trigger_targets(TabSelected(bar.selected), entity);
+                });
+            }
+
+            // Spawn the control bar
+            ui.spawn((
+                UiLayout::window().x(Rl(10.0)).size(Rl((80.0, 100.0))).pack(),
+            )).with_children(|ui| {
+
+                let pos = 100.0 / self.tabs.len() as f32;
+                for (index, tab) in self.tabs.iter().enumerate() {
+
+                    // Spawn the button
+                    ui.spawn((
+                        Name::new(tab.clone()),
+                        UiLayout::window().x(Rl(pos * index as f32)).size(Rl((pos, 100.0))).pack(),
+                        OnHoverSetCursor::new(SystemCursorIcon::Pointer),
+                        Focusable::new(),
+                        TabButton(index),
+                    )).with_children(|ui| {
+
+                        // Spawn the background
+                        ui.spawn((
+                            UiLayout::window().full().y(Rl(10.0)).height(Rl(80.0)).pack(),
+                            UiHover::new().forward_speed(20.0).backward_speed(5.0),
+                            UiColor::new(vec![
+                                (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.0)),
+                                (UiHover::id(), Color::BEVYPUNK_RED.with_alpha(0.4))
+                            ]),
+                            sliced(asset_server, "images/ui/components/button_symetric_sliced.png"),
+                            Pickable::IGNORE,
+                        )).with_children(|ui| {
+
+                            // Spawn the text
+                            ui.spawn((
+                                UiLayout::window().pos(Rl(50.0)).anchor(Anchor::Center).pack(),
+                                UiColor::new(vec![
+                                    (UiBase::id(), Color::BEVYPUNK_RED),
+                                    (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
+                                ]),
+                                UiHover::new().instant(true),
+                                UiTextSize::from(Rh(50.0)),
+                                Text2d::new(tab.to_ascii_uppercase()),
+                                TextFont {
+                                    font: asset_server.load(FONT),
+                                    font_size: 64.0,
+                                    ..default()
+                                },
+                                Pickable::IGNORE,
+                            ));
+                        });
+
+                    // Add the observers, the selected tab stays highlighted
+                    }).observe(hover_set::<Pointer<Over>, true>)
+                    .observe(move |trigger: Trigger<Pointer<Out>>, mut commands: Commands, query: Query<&TabBar>| {
+                        if query.get(entity).is_ok_and(|bar| bar.selected != index) {
+                            commands.trigger_targets(UiHoverSet(false), trigger.target());
+                        }
+                    })
+                    .observe(move |trigger: Trigger<Focused>, mut commands: Commands, query: Query<&TabBar>| {
+                        if !trigger.0 && query.get(entity).is_ok_and(|bar| bar.selected == index) {
+                            commands.trigger_targets(UiHoverSet(true), trigger.target());
+                        }
+                    })
+                    .observe(move |_: Trigger<Activated>, mut commands: Commands, mut query: Query<&mut TabBar>| {
+                        let Ok(mut bar) = query.get_mut(entity) else { return };
+                        bar.selected = index;
+                        commands.trigger_targets(TabSelected(index), entity);
+                    });
+                }
+            });
+        });
+        bar
+    }
+    /// System for keeping only the selected tab highlighted
+    fn system(mut commands: Commands, query: Query<(Entity, &TabBar), Changed<TabBar>>, children: Query<&Children>, buttons: Query<&TabButton>) {
+        for (entity, bar) in &query {
+            for descendant in children.iter_descendants(entity) {
+                if let Ok(button) = buttons.get(descendant) {
+                    commands.trigger_targets(UiHoverSet(button.0 == bar.selected), descendant);
+                }
+            }
+        }
+    }
+}
+
+/// Marker for the tab buttons of [`TabBar`] holding their index
+#[derive(Component)]
+struct TabButton(usize);
+
+/// Triggered on a [`TabBar`] when a tab is selected by the user
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TabSelected(pub usize);
+
+
+
+/// Label with an on/off switch. Triggers [`ToggleChanged`] when clicked.
+#[derive(Component, Reflect, Clone, PartialEq, Debug, Default)]
+pub struct Toggle {
+    pub label: String,
+    pub value: bool,
+}
+impl Toggle {
+    /// Creates new instance
+    pub fn new(label: impl std::fmt::Display, value: bool) -> Self {
+        Self {
+            label: label.to_string(),
+            value,
+        }
+    }
+    /// Layout of the switch head for the given value
+    fn head_layout(value: bool) -> UiLayout {
+        UiLayout::window().x(Rl(if value { 50.0 } else { 0.0 })).size(Rl((50.0, 100.0))).pack()
+    }
+    /// Color of the switch head for the given value
+    fn head_color(value: bool) -> UiColor {
+        UiColor::from(if value { Color::BEVYPUNK_YELLOW.with_alpha(1.2) } else { Color::BEVYPUNK_RED })
+    }
+    /// Spawn the toggle with the given layout.
+    pub fn spawn<'a>(self, ui: &'a mut ChildSpawnerCommands, asset_server: &AssetServer, layout: UiLayout) -> EntityCommands<'a> {
+        let mut toggle = ui.spawn((
+            Name::new(self.label.clone()),
+            layout,
+            OnHoverSetCursor::new(SystemCursorIcon::Pointer),
+            Focusable::new(),
+            self.clone(),
+        ));
+        toggle.with_children(|ui| {
+            spawn_label(ui, asset_server, UiLayout::window().size(Rl((55.0, 100.0))).pack(), &self.label);
+
+            // Spawn the switch
+            ui.spawn((
+                UiLayout::boundary().x1(Rl(57.0)).pos2(Rl(100.0)).pack(),
+            )).with_children(|ui| {
+                ui.spawn((
+                    UiLayout::solid().size((2.0, 1.0)).align_x(-1.0).pack(),
+                    UiHover::new().instant(true),
+                    UiColor::new(vec![
+                        (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.4)),
+                        (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(1.2))
+                    ]),
+                    Sprite::from_image(asset_server.load("images/ui/components/switch_base.png")),
+                    Pickable::IGNORE,
+                )).with_children(|ui| {
+                    ui.spawn((
+                        Toggle::head_layout(self.value),
+                        Toggle::head_color(self.value),
+                        Sprite::from_image(asset_server.load("images/ui/components/switch_head.png")),
+                        Pickable::IGNORE,
+                        ToggleHead,
+                    ));
+                });
+            });
+        }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>)
+        .observe(|trigger: Trigger<Activated>, mut commands: Commands, mut query: Query<&mut Toggle>| {
+            let entity = trigger.target();
+            let Ok(mut toggle) = query.get_mut(entity) else { return };
+            toggle.value = !toggle.value;
+            commands.trigger_targets(ToggleChanged(toggle.value), entity);
+        });
+        toggle
+    }
+    /// System for moving the switch head when the value changes
+    fn system(mut commands: Commands, query: Query<(Entity, &Toggle), Changed<Toggle>>, children: Query<&Children>, heads: Query<(), With<ToggleHead>>) {
+        for (entity, toggle) in &query {
+            for descendant in children.iter_descendants(entity) {
+                if heads.contains(descendant) {
+                    commands.entity(descendant).insert((Toggle::head_layout(toggle.value), Toggle::head_color(toggle.value)));
+                }
+            }
+        }
+    }
+}
+
+/// Marker for the moving part of [`Toggle`]
+#[derive(Component)]
+struct ToggleHead;
+
+/// Triggered on a [`Toggle`] when it is clicked, holds the new value
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ToggleChanged(pub bool);
+
+
+
+/// Label with a horizontal bar in range `0.0..=1.0`. Triggers [`SliderChanged`] when the bar is clicked.
+#[derive(Component, Reflect, Clone, PartialEq, Debug, Default)]
+pub struct Slider {
+    pub label: String,
+    pub value: f32,
+    pub step: f32,
+}
+impl Slider {
+    /// Creates new instance
+    pub fn new(label: impl std::fmt::Display, value: f32) -> Self {
+        Self {
+            label: label.to_string(),
+            value: value.clamp(0.0, 1.0),
+            step: 0.1,
+        }
+    }
+    /// Replace the default step used for snapping the value with a new one.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+    /// Set the value, snapped to the step and clamped.
+    pub fn set(&mut self, value: f32) {
+        let value = if self.step > 0.0 { (value / self.step).round() * self.step } else { value };
+        self.value = value.clamp(0.0, 1.0);
+    }
+    /// Layout of the fill bar for the given value
+    fn fill_layout(value: f32) -> UiLayout {
+        UiLayout::window().size(Rl((value * 100.0, 100.0))).pack()
+    }
+    /// Displayed text for the given value
+    fn text(value: f32) -> String {
+        format!("{}%", (value * 100.0).round())
+    }
+    /// Spawn the slider with the given layout.
+    pub fn spawn<'a>(self, ui: &'a mut ChildSpawnerCommands, asset_server: &AssetServer, layout: UiLayout) -> EntityCommands<'a> {
+        let mut slider = ui.spawn((
+            Name::new(self.label.clone()),
+            layout,
+            self.clone(),
+        ));
+        let entity = slider.id();
+        slider.with_children(|ui| {
+            spawn_label(ui, asset_server, UiLayout::window().size(Rl((55.0, 100.0))).pack(), &self.label);
+
+            // Spawn the track
+            ui.spawn((
+                UiLayout::window().x(Rl(57.0)).size(Rl((43.0, 100.0))).pack(),
+                UiHover::new().instant(true),
+                UiColor::new(vec![
+                    (UiBase::id(), Color::BEVYPUNK_RED.with_alpha(0.15)),
+                    (UiHover::id(), Color::BEVYPUNK_BLUE.with_alpha(0.3))
+                ]),
+                sliced(asset_server, "images/ui/components/button_symetric_sliced.png"),
+                OnHoverSetCursor::new(SystemCursorIcon::Pointer),
+                Focusable::new().adjustable(),
+            )).with_children(|ui| {
+                ui.spawn((
+                    Slider::fill_layout(self.value),
+                    UiColor::from(Color::BEVYPUNK_RED.with_alpha(0.6)),
+                    sliced(asset_server, "images/ui/components/button_symetric_sliced.png"),
+                    Pickable::IGNORE,
+                    SliderFill,
+                ));
+                spawn_value(ui, asset_server, UiLayout::window().pos(Rl(50.0)).anchor(Anchor::Center).pack(), Slider::text(self.value), SliderText);
+
+            }).observe(hover_set::<Pointer<Over>, true>).observe(hover_set::<Pointer<Out>, false>)
+            .observe(move |trigger: Trigger<Pointer<Click>>, mut commands: Commands, tracks: Query<(&GlobalTransform, &Sprite)>, mut query: Query<&mut Slider>| {
+                // Map the hit position on the track to the value
+                let (Some(position), Ok((transform, sprite)), Ok(mut slider)) = (trigger.hit.position, tracks.get(trigger.target()), query.get_mut(entity)) else { return };
+                let width = sprite.custom_size.unwrap_or_default().x;
+                if width <= 0.0 { return; }
+                let local = transform.affine().inverse().transform_point3(position);
+                slider.set(local.x / width + 0.5);
+                commands.trigger_targets(SliderChanged(slider.value), entity);
+            })
+            .observe(move |trigger: Trigger<FocusAdjusted>, mut commands: Commands, mut query: Query<&mut Slider>| {
+                // Move the value by one step in the pressed direction
+                let Ok(mut slider) = query.get_mut(entity) else { return };
+                let value = slider.value + slider.step * trigger.0 as f32;
+                slider.set(value);
+                commands.trigger_targets(SliderChanged(slider.value), entity);
+            });
+        });
+        slider
+    }
+    /// System for resizing the fill bar and text when the value changes
+    fn system(
+        mut commands: Commands,
+        query: Query<(Entity, &Slider), Changed<Slider>>,
+        children: Query<&Children>,
+        fills: Query<(), With<SliderFill>>,
+        mut texts: Query<&mut Text2d, With<SliderText>>,
+    ) {
+        for (entity, slider) in &query {
+            for descendant in children.iter_descendants(entity) {
+                if fills.contains(descendant) {
+                    commands.entity(descendant).insert(Slider::fill_layout(slider.value));
+                }
+                if let Ok(mut text) = texts.get_mut(descendant) {
+                    text.0 = Slider::text(slider.value);
+                }
+            }
+            commands.trigger(RecomputeUiLayout);
+        }
+    }
+}
+
+/// Marker for the fill bar of [`Slider`]
+#[derive(Component)]
+struct SliderFill;
+
+/// Marker for the value text of [`Slider`]
+#[derive(Component)]
+struct SliderText;
+
+/// Triggered on a [`Slider`] when the bar is clicked, holds the new value
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SliderChanged(pub f32);
+
+
+
+/// Modal dialog with a title, body text and a row of buttons. Triggers [`DialogChosen`] with the index of the
+/// pressed button and despawns itself. It is spawned as its own UI layer above the scene and blocks the picking
+/// of everything below. While it is open, only its buttons can be focused.
+#[derive(Component, Reflect, Clone, PartialEq, Debug, Default)]
+pub struct Dialog {
+    pub title: String,
+    pub body: String,
+    pub buttons: Vec<String>,
+    pub default: usize,
+    pub cancel: Option<usize>,
+}
+impl Dialog {
+    /// Depth of the dialog layer above the scene
+    const DEPTH: f32 = 200.0;
+    /// Creates new instance
+    pub fn new(title: impl std::fmt::Display) -> Self {
+        Self {
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+    /// Set the text displayed under the title.
+    pub fn body(mut self, body: impl std::fmt::Display) -> Self {
+        self.body = body.to_string();
+        self
+    }
+    /// Set the buttons from left to right.
+    pub fn buttons(mut self, buttons: impl IntoIterator<Item = impl std::fmt::Display>) -> Self {
+        self.buttons = buttons.into_iter().map(|button| button.to_string()).collect();
+        self
+    }
+    /// Set the button focused when the dialog opens.
+    pub fn default_button(mut self, index: usize) -> Self {
+        self.default = index;
+        self
+    }
+    /// Set the button chosen when the dialog is dismissed with [`DismissDialog`].
+    pub fn cancel_button(mut self, index: usize) -> Self {
+        self.cancel = Some(index);
+        self
+    }
+    /// Spawn the dialog above the UI rendered by camera `0`.
+    pub fn spawn<'a>(self, commands: &'a mut Commands, asset_server: &AssetServer) -> EntityCommands<'a> {
+        let mut dialog = commands.spawn((
+            Name::new(format!("Dialog {}", self.title)),
+            UiLayoutRoot::new_2d(),
+            // Make the UI synchronized with camera viewport size
+            UiFetchFromCamera::<0>,
+            Transform::from_translation(Vec3::Z * Dialog::DEPTH),
+            DialogOrder(DialogOrder::next()),
+            self.clone(),
+        ));
+        let entity = dialog.id();
+        dialog.with_children(|ui| {
+
+            // Dim the screen and block the picking of lower layers
+            ui.spawn((
+                UiLayout::window().full().pack(),
+                Sprite::from_color(Color::BLACK, Vec2::ONE),
+                UiColor::from(Color::BLACK.with_alpha(0.8)),
+            ));
+
+            // Spawn the panel
+            ui.spawn((
+                UiLayout::window().pos(Rl(50.0)).anchor(Anchor::Center).size(Rl((44.0, 30.0))).pack(),
+                UiColor::from(Color::BEVYPUNK_RED.with_alpha(0.15)),
+                sliced(asset_server, "images/ui/components/button_symetric_sliced.png"),
+                Pickable::IGNORE,
+            )).with_children(|ui| {
+
+                // Spawn the title
+                ui.spawn((
+                    // For text always use window layout to position it
+                    UiLayout::window().pos((Rh(10.0), Rl(18.0))).anchor(Anchor::CenterLeft).pack(),
+                    UiColor::from(Color::BEVYPUNK_YELLOW),
+                    UiTextSize::from(Rh(16.0)),
+                    Text2d::new(self.title.to_ascii_uppercase()),
+                    TextFont {
+                        font: asset_server.load(FONT_VALUE),
+                        font_size: 64.0,
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn the body
+                ui.spawn((
+                    UiLayout::window().pos((Rh(10.0), Rl(44.0))).anchor(Anchor::CenterLeft).pack(),
+                    UiColor::from(Color::BEVYPUNK_RED),
+                    UiTextSize::from(Rh(10.0)),
+                    Text2d::new(self.body.clone()),
+                    TextFont {
+                        font: asset_server.load(FONT),
+                        font_size: 64.0,
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                ));
+
+                // Spawn the buttons aligned to the right
+                let gap = 2.0;
+                let size = 30.0;
+                let mut offset = 100.0 - gap - (size + gap) * self.buttons.len() as f32 + gap;
+                for (index, button) in self.buttons.iter().enumerate() {
+                    MenuButton::new(button).centered()
+                        .spawn(ui, asset_server, UiLayout::window().pos(Rl((offset, 68.0))).size(Rl((size, 22.0))).pack())
+                        .insert(DialogButton(index))
+                        .observe(move |_: Trigger<ButtonPressed>, mut commands: Commands| Dialog::choose(&mut commands, entity, index) );
+                    offset += size + gap;
+                }
+            });
+        }).observe(|trigger: Trigger<DismissDialog>, mut commands: Commands, query: Query<&Dialog>| {
+            let Some(cancel) = query.get(trigger.target()).ok().and_then(|dialog| dialog.cancel) else { return };
+            Dialog::choose(&mut commands, trigger.target(), cancel);
+        });
+        dialog
+    }
+    /// Report the choice and close the dialog
+    fn choose(commands: &mut Commands, entity: Entity, index: usize) {
+        // The dialog counts as closed already for the observers of the choice
+        commands.entity(entity).remove::<Dialog>();
+        commands.trigger_targets(DialogChosen(index), entity);
+        commands.entity(entity).despawn();
+    }
+    /// System for focusing the default button when the dialog opens
+    fn system(
+        mut commands: Commands,
+        mut focus: ResMut<Focus>,
+        query: Query<(Entity, &Dialog), Added<Dialog>>,
+        children: Query<&Children>,
+        buttons: Query<&DialogButton>,
+    ) {
+        for (entity, dialog) in &query {
+            let button = children.iter_descendants(entity).find(|descendant| buttons.get(*descendant).is_ok_and(|button| button.0 == dialog.default));
+            if button.is_some() { focus.set(&mut commands, button); }
+        }
+    }
+}
+
+/// Order in which the [`Dialog`] was opened, the newest one is on top
+#[derive(Component, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct DialogOrder(u64);
+impl DialogOrder {
+    /// Take the next number of the spawn counter
+    fn next() -> u64 {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+/// Marker for the buttons of [`Dialog`] holding their index
+#[derive(Component)]
+struct DialogButton(usize);
+
+/// Triggered on a [`Dialog`] when a button is chosen, holds its index
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DialogChosen(pub usize);
+
+/// Trigger on a [`Dialog`] to choose its cancel button, if it has one
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DismissDialog;
+
+
+
+/// Element that can be focused by keyboard and gamepad navigation. Triggers [`Activated`] when clicked or activated.
+/// Focusables are visited by their `order` and then by their position on screen, top to bottom and left to right.
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Debug, Default)]
+pub struct Focusable {
+    pub order: i32,
+    pub adjustable: bool,
+}
+impl Focusable {
+    /// Creates new instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set the order in which the element is visited, lower first.
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+    /// Left and right trigger [`FocusAdjusted`] on the element instead of moving the focus.
+    pub fn adjustable(mut self) -> Self {
+        self.adjustable = true;
+        self
+    }
+    /// Observer for turning clicks into [`Activated`]
+    fn click(trigger: Trigger<Pointer<Click>>, mut commands: Commands, query: Query<(), With<Focusable>>) {
+        if query.contains(trigger.target()) {
+            commands.trigger_targets(Activated, trigger.target());
+        }
+    }
+    /// Observer for moving the focus to the element under the mouse, so navigation continues from there.
+    /// The hidden cursor does not steal the focus during navigation.
+    fn hover(trigger: Trigger<Pointer<Over>>, mut commands: Commands, mut focus: ResMut<Focus>, mode: Res<InputMode>, query: Query<(), With<Focusable>>) {
+        if *mode == InputMode::Navigation { return; }
+        if query.contains(trigger.target()) {
+            focus.set(&mut commands, Some(trigger.target()));
+        }
+    }
+    /// Observer for showing the focus with the same visual and sound as mouse hover
+    fn highlight(trigger: Trigger<Focused>, mut commands: Commands) {
+        commands.trigger_targets(UiHoverSet(trigger.0), trigger.target());
+        if trigger.0 { commands.trigger(UiSound::Hover); }
+    }
+    /// Observer for playing the click sound
+    fn activated(_: Trigger<Activated>, mut commands: Commands) {
+        commands.trigger(UiSound::Click);
+    }
+}
+
+/// The currently focused [`Focusable`]
+#[derive(Resource, Clone, Copy, PartialEq, Debug, Default)]
+pub struct Focus {
+    pub entity: Option<Entity>,
+}
+impl Focus {
+    /// Move the focus to the entity, notifying both the old and the new one.
+    pub fn set(&mut self, commands: &mut Commands, entity: Option<Entity>) {
+        if self.entity == entity { return; }
+        if let Some(old) = self.entity { commands.trigger_targets(Focused(false), old); }
+        if let Some(new) = entity { commands.trigger_targets(Focused(true), new); }
+        self.entity = entity;
+    }
+    /// System for moving the focus and activating the focused element from keyboard and gamepad
+    fn system(
+        mut commands: Commands,
+        mut focus: ResMut<Focus>,
+        mut input: FocusInput,
+        query: Query<(Entity, &Focusable, &GlobalTransform, &InheritedVisibility)>,
+        dialogs: Query<(Entity, &DialogOrder), With<Dialog>>,
+        children: Query<&Children>,
+    ) {
+        // Forget the focus if the element is gone or hidden
+        if focus.entity.is_some_and(|entity| !query.get(entity).is_ok_and(|(_, _, _, visibility)| visibility.get())) {
+            focus.entity = None;
+        }
+
+        // Read the direction, vertical moves the focus and horizontal adjusts
+        let shift = input.keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        let mut vertical = 0;
+        let mut horizontal = 0;
+        if input.keys.just_pressed(KeyCode::ArrowUp) || (shift && input.keys.just_pressed(KeyCode::Tab)) { vertical -= 1; }
+        if input.keys.just_pressed(KeyCode::ArrowDown) || (!shift && input.keys.just_pressed(KeyCode::Tab)) { vertical += 1; }
+        if input.keys.just_pressed(KeyCode::ArrowLeft) { horizontal -= 1; }
+        if input.keys.just_pressed(KeyCode::ArrowRight) { horizontal += 1; }
+        let mut activate = input.keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter]);
+
+        // The left stick moves once per push, it drives the gamepad cursor instead when there is nothing to focus
+        let direction = input.gamepads.iter().map(|gamepad| gamepad.left_stick()).max_by(|a, b| a.length().total_cmp(&b.length())).map_or(IVec2::ZERO, |deflection| {
+            let axis = |value: f32| if value.abs() > InputMode::STICK_THRESHOLD * 2.0 { value.signum() as i32 } else { 0 };
+            IVec2::new(axis(deflection.x), axis(deflection.y))
+        });
+        if direction != *input.stick {
+            if direction.y != 0 && direction.y != input.stick.y { vertical -= direction.y as isize; }
+            if direction.x != 0 && direction.x != input.stick.x { horizontal += direction.x as isize; }
+            *input.stick = direction;
+        }
+
+        for gamepad in &input.gamepads {
+            if gamepad.just_pressed(GamepadButton::DPadUp) { vertical -= 1; }
+            if gamepad.just_pressed(GamepadButton::DPadDown) { vertical += 1; }
+            if gamepad.just_pressed(GamepadButton::DPadLeft) { horizontal -= 1; }
+            if gamepad.just_pressed(GamepadButton::DPadRight) { horizontal += 1; }
+            activate |= gamepad.just_pressed(GamepadButton::South);
+        }
+
+        // Activate the focused element
+        if activate {
+            if let Some(entity) = focus.entity { commands.trigger_targets(Activated, entity); }
+            return;
+        }
+
+        // Adjustable elements consume the horizontal direction
+        if horizontal != 0 {
+            if let Some((entity, _, _, _)) = focus.entity.and_then(|entity| query.get(entity).ok()).filter(|(_, focusable, _, _)| focusable.adjustable) {
+                commands.trigger_targets(FocusAdjusted(horizontal.signum()), entity);
+                horizontal = 0;
+            }
+        }
+        let step = (vertical + horizontal).signum();
+        if step == 0 { return; }
+
+        // Order the visible elements and move through them, an open dialog keeps the focus inside
+        let trap: Option<Vec<Entity>> = dialogs.iter().max_by_key(|(_, order)| **order).map(|(dialog, _)| children.iter_descendants(dialog).collect());
+        let mut list: Vec<(Entity, i32, Vec3)> = query.iter()
+            .filter(|(_, _, _, visibility)| visibility.get())
+            .filter(|(entity, _, _, _)| trap.as_ref().is_none_or(|trap| trap.contains(entity)))
+            .map(|(entity, focusable, transform, _)| (entity, focusable.order, transform.translation()))
+            .collect();
+        if list.is_empty() { return; }
+        list.sort_by(|a, b| a.1.cmp(&b.1).then(b.2.y.total_cmp(&a.2.y)).then(a.2.x.total_cmp(&b.2.x)));
+        let next = match focus.entity.and_then(|entity| list.iter().position(|(candidate, _, _)| *candidate == entity)) {
+            Some(index) => cycle_index(index, list.len(), step),
+            None if step > 0 => 0,
+            None => list.len() - 1,
+        };
+        focus.set(&mut commands, Some(list[next].0));
+    }
+}
+
+/// Keyboard and gamepad input read by [`Focus`]
+#[derive(SystemParam)]
+struct FocusInput<'w, 's> {
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+    /// Direction the left stick was pushed in last frame
+    stick: Local<'s, IVec2>,
+}
+
+/// Triggered on a [`Focusable`] when it gains `true` or loses `false` the focus
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Focused(pub bool);
+
+/// Triggered on a [`Focusable`] when it is clicked or activated with Enter or the gamepad South button
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Activated;
+
+/// Triggered on an adjustable [`Focusable`] when left `-1` or right `1` is pressed
+#[derive(Event, Clone, Copy, Debug)]
+pub struct FocusAdjusted(pub isize);
+
+
+
+/// The way the player is currently pointing at the UI, decided by the last used input device
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum InputMode {
+    /// The software cursor follows the mouse
+    #[default]
+    Mouse,
+    /// The software cursor is moved by the gamepad stick, used when there is nothing to focus
+    Gamepad,
+    /// Keyboard, D-pad or stick [`Focus`] navigation, the cursor is hidden
+    Navigation,
+}
+impl InputMode {
+    /// Mouse movement in pixels needed to switch to the mouse
+    const MOUSE_THRESHOLD: f32 = 2.0;
+    /// Stick deflection needed to switch to the gamepad
+    const STICK_THRESHOLD: f32 = 0.3;
+    /// System for detecting the last used input device
+    fn detect(
+        mut mode: ResMut<InputMode>,
+        mut motion: EventReader<MouseMotion>,
+        mouse: Res<ButtonInput<MouseButton>>,
+        keys: Res<ButtonInput<KeyCode>>,
+        gamepads: Query<&Gamepad>,
+        focusables: Query<&InheritedVisibility, With<Focusable>>,
+    ) {
+        let focusable = focusables.iter().any(|visibility| visibility.get());
+        let moved = motion.read().map(|event| event.delta.length()).sum::<f32>() > InputMode::MOUSE_THRESHOLD;
+        let new = if moved || mouse.get_just_pressed().next().is_some() {
+            InputMode::Mouse
+        } else if gamepads.iter().any(|gamepad| gamepad.left_stick().length() > InputMode::STICK_THRESHOLD) {
+            if focusable { InputMode::Navigation } else { InputMode::Gamepad }
+        } else if keys.any_just_pressed([KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight, KeyCode::Tab])
+            || gamepads.iter().any(|gamepad| gamepad.any_just_pressed([GamepadButton::DPadUp, GamepadButton::DPadDown, GamepadButton::DPadLeft, GamepadButton::DPadRight])) {
+            InputMode::Navigation
+        } else {
+            return;
+        };
+        mode.set_if_neq(new);
+    }
+    /// System for switching the cursor between mouse and gamepad control and hiding it during navigation
+    fn cursor(mut commands: Commands, mode: Res<InputMode>, mut cursors: Query<(Entity, &mut Visibility), With<SoftwareCursor>>) {
+        for (entity, mut visibility) in &mut cursors {
+            match *mode {
+                InputMode::Mouse => { commands.entity(entity).remove::<GamepadCursor>(); },
+                InputMode::Gamepad => { commands.entity(entity).insert(GamepadCursor::new()); },
+                InputMode::Navigation => {},
+            }
+            visibility.set_if_neq(if *mode == InputMode::Navigation { Visibility::Hidden } else { Visibility::Inherited });
+        }
+    }
+}
+
+
+
+/// Sound effect of the user interface, trigger it globally to play the clip assigned in [`UiSounds`]
+#[derive(Event, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum UiSound {
+    /// An element gained focus or mouse hover
+    Hover,
+    /// An element was activated
+    Click,
+    /// The player went back
+    Back,
+    /// The action is not possible or failed
+    Error,
+}
+
+/// Audio clip with its volume and playback rate
+#[derive(Clone, Debug)]
+pub struct UiSoundClip {
+    pub source: Handle<AudioSource>,
+    pub volume: f32,
+    pub rate: f64,
+}
+impl UiSoundClip {
+    /// Creates new instance
+    pub fn new(source: Handle<AudioSource>) -> Self {
+        Self {
+            source,
+            volume: 1.0,
+            rate: 1.0,
+        }
+    }
+    /// Replace the default linear volume with a new one.
+    pub fn volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+    /// Replace the default playback rate with a new one, this also changes the pitch.
+    pub fn rate(mut self, rate: f64) -> Self {
+        self.rate = rate;
+        self
+    }
+}
+
+/// Clips played on the [`UiChannel`] for each [`UiSound`].
+/// Each sound is rate limited on its own, so sweeping over many buttons does not stack them.
+#[derive(Resource, Clone, Debug)]
+pub struct UiSounds {
+    clips: HashMap<UiSound, UiSoundClip>,
+    /// Minimum time between two plays of the same sound
+    pub interval: Duration,
+    /// Real time when each sound was last played
+    last: HashMap<UiSound, Duration>,
+}
+impl Default for UiSounds {
+    fn default() -> Self {
+        Self {
+            clips: HashMap::new(),
+            interval: Duration::from_millis(60),
+            last: HashMap::new(),
+        }
+    }
+}
+impl UiSounds {
+    /// Creates new instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Assign the clip to the sound.
+    pub fn clip(mut self, sound: UiSound, clip: UiSoundClip) -> Self {
+        self.clips.insert(sound, clip);
+        self
+    }
+    /// Replace the default minimum time between two plays of the same sound with a new one.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+    /// Observer for playing the sounds
+    fn play(trigger: Trigger<UiSound>, mut sounds: ResMut<UiSounds>, channel: Res<AudioChannel<UiChannel>>, time: Res<Time<Real>>) {
+        let now = time.elapsed();
+        let sound = *trigger.event();
+        if sounds.last.get(&sound).is_some_and(|last| now.saturating_sub(*last) < sounds.interval) { return; }
+        let Some(clip) = sounds.clips.get(&sound) else { return };
+        channel.play(clip.source.clone()).with_volume(decibels(clip.volume)).with_playback_rate(clip.rate);
+        sounds.last.insert(sound, now);
+    }
+}
+
+
+
+/// Plugin with systems for the game widgets
+pub struct WidgetPlugin;
+impl Plugin for WidgetPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, ChevronSelector::system)
+            .add_systems(Update, TabBar::system)
+            .add_systems(Update, Toggle::system)
+            .add_systems(Update, Slider::system)
+            .init_resource::<Focus>()
+            .add_observer(Focusable::click)
+            .add_observer(Focusable::hover)
+            .add_observer(Focusable::highlight)
+            .add_observer(Focusable::activated)
+            .init_resource::<UiSounds>()
+            .add_observer(UiSounds::play)
+            .add_systems(Update, Focus::system)
+            .add_systems(Update, Dialog::system)
+            .init_resource::<InputMode>()
+            .add_systems(Update, (InputMode::detect, InputMode::cursor.run_if(resource_changed::<InputMode>)).chain());
+    }
+}